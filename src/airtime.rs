@@ -0,0 +1,181 @@
+//! Time on air of packets and the minimum auto retransmit delay.
+//!
+//! A packet on air is a preamble, the address, the 9-bit packet control field, the payload and
+//! the CRC, see [`esb`](crate::esb). Its duration follows from the address width in
+//! [`SETUP_AW`](registers::SetupAw), the CRC length in [`CONFIG`](registers::Config) and the
+//! data rate in [`RF_SETUP`](registers::RfSetup).
+//!
+//! | Data rate | Bit time |
+//! |-----------|----------|
+//! | 250kbps   | 4 µs     |
+//! | 1Mbps     | 1 µs     |
+//! | 2Mbps     | 0.5 µs   |
+//!
+//! ## Example
+//! ```rust
+//! use nrf24l01_commands::{airtime, fields, registers};
+//!
+//! let setup_aw = registers::SetupAw::new();
+//! let config = registers::Config::new().with_crco(fields::Crco::TwoByte);
+//! let rf_setup = registers::RfSetup::new().with_rf_dr_high(fields::RfDrHigh::Mbps1);
+//!
+//! // 8 + 5 * 8 + 9 + 32 * 8 + 2 * 8 bits at 1Mbps
+//! assert_eq!(airtime::time_on_air_ns(setup_aw, config, rf_setup, 32), Ok(329_000));
+//!
+//! // An 8 byte ACK payload at 1Mbps does not fit in 250µs
+//! let ard = airtime::min_ard(rf_setup, 8);
+//! assert_eq!(ard, fields::AutoRetransmitDelay::US500);
+//! assert_eq!(airtime::ard_us(ard), 500);
+//! ```
+use crate::esb::{self, Error};
+use crate::fields;
+use crate::registers;
+
+/// Length of the preamble in bits.
+const PREAMBLE_BITS: usize = 8;
+/// Length of the packet control field in bits.
+const PCF_BITS: usize = 9;
+
+/// Duration of one bit at the data rate of `rf_setup`, in nanoseconds.
+pub const fn bit_time_ns(rf_setup: registers::RfSetup) -> u32 {
+    match rf_setup.effective_data_rate() {
+        fields::DataRate::Kbps250 => 4000,
+        fields::DataRate::Mbps1 => 1000,
+        fields::DataRate::Mbps2 => 500,
+    }
+}
+
+/// Length of a packet on air in bits.
+///
+/// The CRC length is taken from `en_crc` and `crco`. Note that the chip forces CRC on when
+/// any pipe has auto-ACK enabled.
+pub const fn packet_bits(
+    setup_aw: registers::SetupAw,
+    config: registers::Config,
+    payload_len: usize,
+) -> Result<usize, Error> {
+    if payload_len > esb::MAX_PAYLOAD_LEN {
+        return Err(Error::PayloadTooLong);
+    }
+    let format = esb::Format {
+        address_width: setup_aw.aw(),
+        crc: config.crco(),
+    };
+    let address_len = match format.address_len() {
+        Ok(len) => len,
+        Err(e) => return Err(e),
+    };
+    let crc_len = if config.en_crc() { format.crc_len() } else { 0 };
+    Ok(PREAMBLE_BITS + address_len * 8 + PCF_BITS + payload_len * 8 + crc_len * 8)
+}
+
+/// Duration of a packet on air in nanoseconds.
+pub const fn time_on_air_ns(
+    setup_aw: registers::SetupAw,
+    config: registers::Config,
+    rf_setup: registers::RfSetup,
+    payload_len: usize,
+) -> Result<u32, Error> {
+    match packet_bits(setup_aw, config, payload_len) {
+        Ok(bits) => Ok(bits as u32 * bit_time_ns(rf_setup)),
+        Err(e) => Err(e),
+    }
+}
+
+/// Duration of a packet on air in microseconds, rounded up.
+pub const fn time_on_air_us(
+    setup_aw: registers::SetupAw,
+    config: registers::Config,
+    rf_setup: registers::RfSetup,
+    payload_len: usize,
+) -> Result<u32, Error> {
+    match time_on_air_ns(setup_aw, config, rf_setup, payload_len) {
+        Ok(ns) => Ok(ns.div_ceil(1000)),
+        Err(e) => Err(e),
+    }
+}
+
+/// Minimum auto retransmit delay for the data rate and ACK payload size, from the datasheet.
+///
+/// - 250kbps: 500µs without ACK payload, plus 250µs per started 8 bytes of ACK payload.
+/// - 1Mbps: 250µs fits an ACK payload of up to 5 bytes, 500µs any ACK payload.
+/// - 2Mbps: 250µs fits an ACK payload of up to 15 bytes, 500µs any ACK payload.
+pub const fn min_ard(rf_setup: registers::RfSetup, ack_payload: u8) -> fields::AutoRetransmitDelay {
+    let bits = match rf_setup.effective_data_rate() {
+        // 250kbps requires 500µs even without ACK payload
+        fields::DataRate::Kbps250 => match ack_payload {
+            0 => 1,
+            1..=8 => 2,
+            9..=16 => 3,
+            17..=24 => 4,
+            _ => 5,
+        },
+        fields::DataRate::Mbps1 => (ack_payload > 5) as u8,
+        fields::DataRate::Mbps2 => (ack_payload > 15) as u8,
+    };
+    fields::AutoRetransmitDelay::from_bits(bits)
+}
+
+/// Auto retransmit delay in microseconds.
+pub const fn ard_us(ard: fields::AutoRetransmitDelay) -> u32 {
+    (ard.into_bits() as u32 + 1) * 250
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_time_on_air() {
+        let setup_aw = registers::SetupAw::new();
+        let config = registers::Config::new().with_crco(fields::Crco::TwoByte);
+        let mbps1 = registers::RfSetup::new().with_rf_dr_high(fields::RfDrHigh::Mbps1);
+        let mbps2 = registers::RfSetup::new();
+        let kbps250 = registers::RfSetup::new().with_rf_dr_low(true);
+
+        assert_eq!(packet_bits(setup_aw, config, 32), Ok(329));
+        assert_eq!(time_on_air_ns(setup_aw, config, mbps2, 32), Ok(164_500));
+        assert_eq!(time_on_air_us(setup_aw, config, mbps2, 32), Ok(165));
+        assert_eq!(time_on_air_us(setup_aw, config, kbps250, 32), Ok(1316));
+        let kbps250 = kbps250.with_data_rate(fields::DataRate::Kbps250);
+        assert_eq!(time_on_air_us(setup_aw, config, kbps250, 32), Ok(1316));
+
+        // Empty ACK with a 3 byte address and no CRC
+        let setup_aw = setup_aw.with_aw(fields::AddressWidth::ThreeByte);
+        let config = config.with_en_crc(false);
+        assert_eq!(packet_bits(setup_aw, config, 0), Ok(41));
+        assert_eq!(time_on_air_us(setup_aw, config, mbps1, 0), Ok(41));
+
+        // 1 byte CRC
+        let config = config.with_en_crc(true).with_crco(fields::Crco::OneByte);
+        assert_eq!(packet_bits(setup_aw, config, 1), Ok(57));
+
+        assert_eq!(
+            packet_bits(setup_aw, config, 33),
+            Err(Error::PayloadTooLong)
+        );
+        let illegal = setup_aw.with_aw(fields::AddressWidth::Illegal);
+        assert_eq!(
+            packet_bits(illegal, config, 1),
+            Err(Error::IllegalAddressWidth)
+        );
+    }
+
+    #[test]
+    fn test_min_ard() {
+        let mbps1 = registers::RfSetup::new().with_rf_dr_high(fields::RfDrHigh::Mbps1);
+        let mbps2 = registers::RfSetup::new();
+        let kbps250 = registers::RfSetup::new().with_rf_dr_low(true);
+
+        assert_eq!(min_ard(mbps2, 15), fields::AutoRetransmitDelay::US250);
+        assert_eq!(min_ard(mbps2, 16), fields::AutoRetransmitDelay::US500);
+        assert_eq!(min_ard(mbps1, 5), fields::AutoRetransmitDelay::US250);
+        assert_eq!(min_ard(mbps1, 32), fields::AutoRetransmitDelay::US500);
+        assert_eq!(min_ard(kbps250, 0), fields::AutoRetransmitDelay::US500);
+        assert_eq!(min_ard(kbps250, 9), fields::AutoRetransmitDelay::US1000);
+        assert_eq!(min_ard(kbps250, 32), fields::AutoRetransmitDelay::US1500);
+
+        assert_eq!(ard_us(fields::AutoRetransmitDelay::US250), 250);
+        assert_eq!(ard_us(fields::AutoRetransmitDelay::US4000), 4000);
+    }
+}
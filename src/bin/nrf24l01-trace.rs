@@ -0,0 +1,434 @@
+//! Annotate SPI captures of an nRF24L01+ exported from sigrok-cli or Saleae Logic 2.
+//!
+//! ```text
+//! nrf24l01-trace [--format sigrok|saleae] [--setup-aw 3|4|5] [FILE]
+//! ```
+//!
+//! Reads `FILE`, or stdin, and prints one line per transaction with the status register, the
+//! command and the fields of the register it accesses. The format is detected from the input
+//! unless given.
+//!
+//! ### sigrok-cli
+//! The SPI decoder's transfer annotations, with their class names:
+//! ```text
+//! sigrok-cli -i capture.sr -P spi:clk=D0:mosi=D1:miso=D2:cs=D3 \
+//!     -A spi=mosi-transfer:miso-transfer --protocol-decoder-ann-class
+//! ```
+//!
+//! ### Saleae Logic 2
+//! The CSV export of the SPI analyzer, with `enable`/`disable` rows framing each transaction
+//! and hex or decimal `mosi`/`miso` columns.
+//!
+//! The register state is followed across the trace, so address registers are decoded with the
+//! current `SETUP_AW` and `R_RX_PAYLOAD` with the payload width of its pipe: the last
+//! `R_RX_PL_WID` if dynamic payload length is enabled on the pipe, `RX_PW_Px` otherwise.
+use nrf24l01_commands::fields::{AddressWidth, RxPipeNo};
+use nrf24l01_commands::registers;
+use nrf24l01_commands::trace::{Decoded, Disassembler, RegisterValue, Transaction};
+use std::fmt::Write as _;
+use std::io::{self, Read};
+use std::{env, fs, process};
+
+const USAGE: &str = "usage: nrf24l01-trace [--format sigrok|saleae] [--setup-aw 3|4|5] [FILE]";
+
+/// Export format of the capture.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Format {
+    Sigrok,
+    Saleae,
+}
+
+impl Format {
+    /// Detect the format from the first line, which is the CSV header of a Saleae export.
+    fn detect(input: &str) -> Self {
+        let header = input.lines().find(|line| !line.trim().is_empty());
+        match header {
+            Some(line) if csv_fields(line).any(|field| field.eq_ignore_ascii_case("type")) => {
+                Self::Saleae
+            }
+            _ => Self::Sigrok,
+        }
+    }
+}
+
+/// Bytes exchanged during one CSN assertion.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct SpiTransaction {
+    mosi: Vec<u8>,
+    miso: Vec<u8>,
+}
+
+/// Parse the `mosi-transfer` and `miso-transfer` annotations of the sigrok SPI decoder.
+///
+/// Other annotation classes are ignored.
+fn parse_sigrok(input: &str) -> Result<Vec<SpiTransaction>, String> {
+    let mut transactions = Vec::new();
+    let (mut mosi, mut miso) = (None, None);
+    for (i, line) in input.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let error = |msg: &str| format!("line {}: {msg}", i + 1);
+        // spi-1: mosi-transfer: "20 0A"
+        let mut parts = line.splitn(3, ": ");
+        let (Some(_decoder), Some(class), Some(data)) = (parts.next(), parts.next(), parts.next())
+        else {
+            return Err(error(
+                "expected annotation classes, run with --protocol-decoder-ann-class",
+            ));
+        };
+        let slot = match class {
+            "mosi-transfer" => &mut mosi,
+            "miso-transfer" => &mut miso,
+            _ => continue,
+        };
+        if slot.is_some() {
+            return Err(error(&format!("{class} without a matching transfer")));
+        }
+        let bytes = data
+            .trim_matches('"')
+            .split_whitespace()
+            .map(|byte| u8::from_str_radix(byte, 16))
+            .collect::<Result<_, _>>()
+            .map_err(|_| error(&format!("invalid byte in {data}")))?;
+        *slot = Some(bytes);
+
+        if let (Some(_), Some(_)) = (&mosi, &miso) {
+            transactions.push(SpiTransaction {
+                mosi: mosi.take().unwrap(),
+                miso: miso.take().unwrap(),
+            });
+        }
+    }
+    match (mosi, miso) {
+        (None, None) => Ok(transactions),
+        _ => Err("trace ends with an unmatched transfer".into()),
+    }
+}
+
+/// Parse the CSV export of the Saleae Logic 2 SPI analyzer.
+///
+/// Bytes outside an `enable`/`disable` pair, e.g. when CSN was not captured, form a single
+/// transaction.
+fn parse_saleae(input: &str) -> Result<Vec<SpiTransaction>, String> {
+    let mut lines = input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty());
+    let Some((_, header)) = lines.next() else {
+        return Ok(Vec::new());
+    };
+    let column = |name: &str| {
+        csv_fields(header)
+            .position(|field| field.eq_ignore_ascii_case(name))
+            .ok_or(format!("missing column {name}"))
+    };
+    let (ty, mosi, miso) = (column("type")?, column("mosi")?, column("miso")?);
+
+    let mut transactions = Vec::new();
+    let mut current = SpiTransaction::default();
+    for (i, line) in lines {
+        let error = |msg: &str| format!("line {}: {msg}", i + 1);
+        let fields: Vec<_> = csv_fields(line).collect();
+        let field = |index: usize| fields.get(index).copied().unwrap_or_default();
+        match field(ty) {
+            "enable" | "disable" if !current.mosi.is_empty() => {
+                transactions.push(std::mem::take(&mut current));
+            }
+            "result" => {
+                let byte = |index| parse_byte(field(index)).ok_or_else(|| error("invalid byte"));
+                current.mosi.push(byte(mosi)?);
+                current.miso.push(byte(miso)?);
+            }
+            _ => {}
+        }
+    }
+    if !current.mosi.is_empty() {
+        transactions.push(current);
+    }
+    Ok(transactions)
+}
+
+/// Fields of a CSV line, without quotes.
+fn csv_fields(line: &str) -> impl Iterator<Item = &str> {
+    line.split(',').map(|field| field.trim().trim_matches('"'))
+}
+
+/// Parse a `0x` hex, `0b` binary or decimal byte.
+fn parse_byte(s: &str) -> Option<u8> {
+    if let Some(hex) = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        u8::from_str_radix(hex, 16).ok()
+    } else if let Some(bin) = s.strip_prefix("0b") {
+        u8::from_str_radix(bin, 2).ok()
+    } else {
+        s.parse().ok()
+    }
+}
+
+/// Register state followed across the trace to find the width of RX payloads.
+#[derive(Default)]
+struct RegisterState {
+    dynpd: registers::Dynpd,
+    feature: registers::Feature,
+    rx_pw: [u8; 6],
+    rx_pl_wid: Option<u8>,
+}
+
+impl RegisterState {
+    /// Apply a transaction to the state.
+    fn update(&mut self, transaction: &Transaction) {
+        match transaction.command {
+            Decoded::RRegister(register) | Decoded::WRegister(register) => match register {
+                RegisterValue::Dynpd(dynpd) => self.dynpd = dynpd,
+                RegisterValue::Feature(feature) => self.feature = feature,
+                RegisterValue::RxPwP0(reg) => self.rx_pw[0] = reg.rx_pw_p0(),
+                RegisterValue::RxPwP1(reg) => self.rx_pw[1] = reg.rx_pw_p1(),
+                RegisterValue::RxPwP2(reg) => self.rx_pw[2] = reg.rx_pw_p2(),
+                RegisterValue::RxPwP3(reg) => self.rx_pw[3] = reg.rx_pw_p3(),
+                RegisterValue::RxPwP4(reg) => self.rx_pw[4] = reg.rx_pw_p4(),
+                RegisterValue::RxPwP5(reg) => self.rx_pw[5] = reg.rx_pw_p5(),
+                _ => {}
+            },
+            Decoded::RRxPlWid(width) => self.rx_pl_wid = Some(width),
+            Decoded::RRxPayload(_) | Decoded::FlushRx => self.rx_pl_wid = None,
+            _ => {}
+        }
+    }
+
+    /// Payload width of the pipe at the head of the RX FIFO, and whether it is dynamic.
+    fn rx_payload_width(&self, pipe: RxPipeNo) -> Option<(usize, bool)> {
+        let pipe = pipe.into_bits();
+        if pipe > 5 {
+            return None;
+        }
+        let dynamic = self.feature.en_dpl() && self.dynpd.into_bits() >> pipe & 1 == 1;
+        if dynamic {
+            self.rx_pl_wid.map(|width| (width as usize, true))
+        } else {
+            Some((self.rx_pw[pipe as usize] as usize, false))
+        }
+    }
+}
+
+/// Annotates transactions.
+#[derive(Default)]
+struct Annotator {
+    disassembler: Disassembler,
+    state: RegisterState,
+}
+
+impl Annotator {
+    /// Decode and annotate one transaction.
+    fn annotate(&mut self, spi: &SpiTransaction) -> String {
+        let transaction = match self.disassembler.decode(&spi.mosi, &spi.miso) {
+            Ok(transaction) => transaction,
+            Err(e) => return format!("{:#04X}  error: {e:?}", spi.miso.first().unwrap_or(&0)),
+        };
+        let mut line = format!("{:#04X}  {transaction}", transaction.status.into_bits());
+        match transaction.command {
+            Decoded::RRxPayload(payload) => {
+                let pipe = transaction.status.rx_p_no();
+                match self.state.rx_payload_width(pipe) {
+                    Some((width, dynamic)) => {
+                        write_hex(&mut line, &payload[..width.min(payload.len())]);
+                        let kind = if dynamic { "dynamic" } else { "static" };
+                        let _ = write!(line, "  ; {pipe:?}, {kind} width {width}");
+                        if payload.len() > width {
+                            let _ = write!(line, ", {} bytes past payload", payload.len() - width);
+                        } else if payload.len() < width {
+                            let _ = write!(line, ", {} bytes short", width - payload.len());
+                        }
+                    }
+                    None => {
+                        write_hex(&mut line, payload);
+                        let _ = write!(line, "  ; {pipe:?}, unknown width");
+                    }
+                }
+            }
+            Decoded::WTxPayload(payload)
+            | Decoded::WTxPayloadNoack(payload)
+            | Decoded::WAckPayload { payload, .. } => write_hex(&mut line, payload),
+            _ => {}
+        }
+        self.state.update(&transaction);
+        line
+    }
+}
+
+/// Append bytes in hex.
+fn write_hex(line: &mut String, bytes: &[u8]) {
+    for byte in bytes {
+        let _ = write!(line, " {byte:02X}");
+    }
+}
+
+fn main() {
+    if let Err(e) = run() {
+        eprintln!("nrf24l01-trace: {e}");
+        process::exit(1);
+    }
+}
+
+fn run() -> Result<(), String> {
+    let mut format = None;
+    let mut setup_aw = registers::SetupAw::new();
+    let mut path = None;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => match args.next().as_deref() {
+                Some("sigrok") => format = Some(Format::Sigrok),
+                Some("saleae") => format = Some(Format::Saleae),
+                _ => return Err(USAGE.into()),
+            },
+            "--setup-aw" => {
+                let aw = match args.next().as_deref() {
+                    Some("3") => AddressWidth::ThreeByte,
+                    Some("4") => AddressWidth::FourByte,
+                    Some("5") => AddressWidth::FiveByte,
+                    _ => return Err(USAGE.into()),
+                };
+                setup_aw = setup_aw.with_aw(aw);
+            }
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return Ok(());
+            }
+            _ if path.is_none() && !arg.starts_with("--") => path = Some(arg),
+            _ => return Err(USAGE.into()),
+        }
+    }
+
+    let input = match &path {
+        Some(path) => fs::read_to_string(path).map_err(|e| format!("{path}: {e}"))?,
+        None => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|e| e.to_string())?;
+            input
+        }
+    };
+    let transactions = match format.unwrap_or_else(|| Format::detect(&input)) {
+        Format::Sigrok => parse_sigrok(&input)?,
+        Format::Saleae => parse_saleae(&input)?,
+    };
+
+    let mut annotator = Annotator {
+        disassembler: Disassembler::with_setup_aw(setup_aw),
+        ..Default::default()
+    };
+    for (i, transaction) in transactions.iter().enumerate() {
+        println!("{i:>5}  {}", annotator.annotate(transaction));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_sigrok() {
+        let input = "\
+spi-1: miso-transfer: \"0E 08\"
+spi-1: mosi-transfer: \"00 00\"
+spi-1: mosi-data: \"20\"
+spi-1: mosi-transfer: \"A0 01 02\"
+spi-1: miso-transfer: \"0E 0E 0E\"
+";
+        assert_eq!(Format::detect(input), Format::Sigrok);
+        let transactions = parse_sigrok(input).unwrap();
+        assert_eq!(
+            transactions,
+            [
+                SpiTransaction {
+                    mosi: vec![0x00, 0x00],
+                    miso: vec![0x0E, 0x08]
+                },
+                SpiTransaction {
+                    mosi: vec![0xA0, 1, 2],
+                    miso: vec![0x0E; 3]
+                },
+            ]
+        );
+
+        assert!(parse_sigrok("spi-1: 20 0A\n").is_err());
+        assert!(parse_sigrok("spi-1: mosi-transfer: \"FF\"\n").is_err());
+        assert!(
+            parse_sigrok("spi-1: mosi-transfer: \"FF\"\nspi-1: mosi-transfer: \"FF\"\n").is_err()
+        );
+    }
+
+    #[test]
+    fn test_parse_saleae() {
+        let input = "\
+name,type,start_time,duration,\"mosi\",\"miso\"
+\"SPI\",\"enable\",0.001,2e-08,,
+\"SPI\",\"result\",0.00101,8e-06,0x20,0x0E
+\"SPI\",\"result\",0.00102,8e-06,0x0A,0x00
+\"SPI\",\"disable\",0.00103,2e-08,,
+\"SPI\",\"enable\",0.002,2e-08,,
+\"SPI\",\"result\",0.00201,8e-06,255,14
+\"SPI\",\"disable\",0.00202,2e-08,,
+";
+        assert_eq!(Format::detect(input), Format::Saleae);
+        let transactions = parse_saleae(input).unwrap();
+        assert_eq!(
+            transactions,
+            [
+                SpiTransaction {
+                    mosi: vec![0x20, 0x0A],
+                    miso: vec![0x0E, 0x00]
+                },
+                SpiTransaction {
+                    mosi: vec![0xFF],
+                    miso: vec![0x0E]
+                },
+            ]
+        );
+
+        assert!(parse_saleae("type,mosi\n").is_err());
+        assert!(parse_saleae("type,mosi,miso\nresult,0xZZ,0\n").is_err());
+    }
+
+    #[test]
+    fn test_annotate_rx_payload() {
+        let spi = |mosi: &[u8], miso: &[u8]| SpiTransaction {
+            mosi: mosi.to_vec(),
+            miso: miso.to_vec(),
+        };
+        let mut annotator = Annotator::default();
+
+        // Static width of pipe 1 is 2, the firmware reads 4 bytes
+        annotator.annotate(&spi(&[0x32, 2], &[0x0E, 0]));
+        let line = annotator.annotate(&spi(&[0x61, 0, 0, 0, 0], &[0x42, 1, 2, 3, 4]));
+        assert_eq!(
+            line,
+            "0x42  R_RX_PAYLOAD -> [4 bytes] 01 02  ; Pipe1, static width 2, 2 bytes past payload"
+        );
+
+        // Dynamic payload length on pipe 1, width from R_RX_PL_WID
+        annotator.annotate(&spi(&[0x3D, 0b100], &[0x0E, 0]));
+        annotator.annotate(&spi(&[0x3C, 0b10], &[0x0E, 0]));
+        annotator.annotate(&spi(&[0x60, 0], &[0x42, 3]));
+        let line = annotator.annotate(&spi(&[0x61, 0, 0, 0], &[0x42, 7, 8, 9]));
+        assert_eq!(
+            line,
+            "0x42  R_RX_PAYLOAD -> [3 bytes] 07 08 09  ; Pipe1, dynamic width 3"
+        );
+
+        // Width is unknown until the next R_RX_PL_WID
+        let line = annotator.annotate(&spi(&[0x61, 0], &[0x42, 7]));
+        assert_eq!(
+            line,
+            "0x42  R_RX_PAYLOAD -> [1 bytes] 07  ; Pipe1, unknown width"
+        );
+
+        // Address registers follow SETUP_AW
+        annotator.annotate(&spi(&[0x23, 0b01], &[0x0E, 0]));
+        let line = annotator.annotate(&spi(&[0x30, 1, 2, 3], &[0x0E, 0, 0, 0]));
+        assert_eq!(line, "0x0E  W_REGISTER TX_ADDR 0x030201");
+    }
+}
@@ -0,0 +1,240 @@
+//! RF channels, their carrier frequency and regulatory limits.
+//!
+//! The carrier of channel `n` in [`RF_CH`](registers::RfCh) is 2400 + `n` MHz, for channels
+//! 0 - 125. A link occupies about 1 MHz at 250kbps and 1Mbps, and 2 MHz at 2Mbps, so links at
+//! 2Mbps need channels at least 2 MHz apart to not overlap.
+//!
+//! | Region            | Band               | Channels |
+//! |-------------------|--------------------|----------|
+//! | [`Region::Etsi`]  | 2400 - 2483.5 MHz  | 0 - 83   |
+//! | [`Region::Fcc`]   | 2400 - 2483.5 MHz  | 0 - 83   |
+//!
+//! ## Example
+//! ```rust
+//! use nrf24l01_commands::{channel::{self, Channel, Region}, fields};
+//!
+//! let channel = Channel::from_mhz(2476).unwrap();
+//! assert_eq!(channel.number(), 76);
+//! assert_eq!(channel.rf_ch().into_bits(), 76);
+//! assert!(channel.check_region(Region::Etsi).is_ok());
+//!
+//! // Above 2483.5 MHz
+//! let channel = Channel::new(100).unwrap();
+//! assert_eq!(channel.to_mhz(), 2500);
+//! assert!(channel.check_region(Region::Fcc).is_err());
+//!
+//! // Two links at 2Mbps overlap 1 MHz apart
+//! let a = Channel::new(76).unwrap();
+//! let b = Channel::new(77).unwrap();
+//! assert!(channel::check_spacing(a, b, fields::DataRate::Mbps1).is_ok());
+//! assert_eq!(
+//!     channel::check_spacing(a, b, fields::DataRate::Mbps2),
+//!     Err(channel::Error::TooClose { a, b })
+//! );
+//! ```
+use crate::{fields, registers};
+
+/// Carrier frequency of channel 0 in MHz.
+pub const BASE_MHZ: u16 = 2400;
+/// Highest channel number.
+pub const MAX_CHANNEL: u8 = 125;
+
+/// Channel error.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// Channel number above 125, or frequency outside of 2400 - 2525 MHz.
+    OutOfRange,
+    /// Channel outside of the band of a region.
+    NotAllowed {
+        /// The channel.
+        channel: Channel,
+        /// The region.
+        region: Region,
+    },
+    /// Two channels are closer than the bandwidth of the data rate.
+    TooClose {
+        /// First channel.
+        a: Channel,
+        /// Second channel.
+        b: Channel,
+    },
+}
+
+/// Regulatory region, restricting the band that may be used.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Region {
+    /// ETSI EN 300 328, 2400 - 2483.5 MHz.
+    Etsi,
+    /// FCC part 15.247, 2400 - 2483.5 MHz.
+    Fcc,
+    /// Custom band of carrier frequencies, inclusive.
+    Custom {
+        /// Lowest allowed carrier frequency in MHz.
+        min_mhz: u16,
+        /// Highest allowed carrier frequency in MHz.
+        max_mhz: u16,
+    },
+}
+
+impl Region {
+    /// Lowest and highest allowed carrier frequency in MHz.
+    pub const fn band_mhz(self) -> (u16, u16) {
+        match self {
+            // Channel 83 is the last carrier below 2483.5 MHz
+            Self::Etsi | Self::Fcc => (BASE_MHZ, BASE_MHZ + 83),
+            Self::Custom { min_mhz, max_mhz } => (min_mhz, max_mhz),
+        }
+    }
+}
+
+/// RF channel 0 - 125.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Channel(u8);
+
+impl Channel {
+    /// Create a channel from its number.
+    pub const fn new(number: u8) -> Result<Self, Error> {
+        if number > MAX_CHANNEL {
+            return Err(Error::OutOfRange);
+        }
+        Ok(Self(number))
+    }
+
+    /// Create a channel from its carrier frequency in MHz.
+    pub const fn from_mhz(mhz: u16) -> Result<Self, Error> {
+        if mhz < BASE_MHZ || mhz > BASE_MHZ + MAX_CHANNEL as u16 {
+            return Err(Error::OutOfRange);
+        }
+        Ok(Self((mhz - BASE_MHZ) as u8))
+    }
+
+    /// Create a channel from the [`RF_CH`](registers::RfCh) register.
+    pub const fn from_rf_ch(rf_ch: registers::RfCh) -> Result<Self, Error> {
+        Self::new(rf_ch.rf_ch())
+    }
+
+    /// Channel number.
+    pub const fn number(self) -> u8 {
+        self.0
+    }
+
+    /// Carrier frequency in MHz.
+    pub const fn to_mhz(self) -> u16 {
+        BASE_MHZ + self.0 as u16
+    }
+
+    /// [`RF_CH`](registers::RfCh) register set to the channel.
+    pub const fn rf_ch(self) -> registers::RfCh {
+        registers::RfCh::new().with_rf_ch(self.0)
+    }
+
+    /// Check that the carrier is in the band of `region`.
+    pub const fn check_region(self, region: Region) -> Result<(), Error> {
+        let (min_mhz, max_mhz) = region.band_mhz();
+        let mhz = self.to_mhz();
+        if mhz < min_mhz || mhz > max_mhz {
+            return Err(Error::NotAllowed {
+                channel: self,
+                region,
+            });
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<registers::RfCh> for Channel {
+    type Error = Error;
+
+    fn try_from(rf_ch: registers::RfCh) -> Result<Self, Error> {
+        Self::from_rf_ch(rf_ch)
+    }
+}
+
+impl From<Channel> for registers::RfCh {
+    fn from(channel: Channel) -> Self {
+        channel.rf_ch()
+    }
+}
+
+/// Bandwidth of a link at `data_rate`, i.e. the minimum spacing between channels in MHz.
+pub const fn min_spacing_mhz(data_rate: fields::DataRate) -> u16 {
+    match data_rate {
+        fields::DataRate::Kbps250 | fields::DataRate::Mbps1 => 1,
+        fields::DataRate::Mbps2 => 2,
+    }
+}
+
+/// Check that links on channels `a` and `b` at `data_rate` don't overlap.
+pub const fn check_spacing(
+    a: Channel,
+    b: Channel,
+    data_rate: fields::DataRate,
+) -> Result<(), Error> {
+    if a.to_mhz().abs_diff(b.to_mhz()) < min_spacing_mhz(data_rate) {
+        return Err(Error::TooClose { a, b });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_channel() {
+        assert_eq!(Channel::new(0).unwrap().to_mhz(), 2400);
+        assert_eq!(Channel::new(125).unwrap().to_mhz(), 2525);
+        assert_eq!(Channel::new(126), Err(Error::OutOfRange));
+        assert_eq!(Channel::from_mhz(2402), Channel::new(2));
+        assert_eq!(Channel::from_mhz(2399), Err(Error::OutOfRange));
+        assert_eq!(Channel::from_mhz(2526), Err(Error::OutOfRange));
+
+        // RF_CH is 7 bits wide
+        let rf_ch = registers::RfCh::new().with_rf_ch(127);
+        assert_eq!(Channel::try_from(rf_ch), Err(Error::OutOfRange));
+        let channel = Channel::try_from(registers::RfCh::new()).unwrap();
+        assert_eq!(channel.number(), 2);
+        assert_eq!(registers::RfCh::from(channel).into_bits(), 2);
+    }
+
+    #[test]
+    fn test_region() {
+        let last = Channel::new(83).unwrap();
+        assert_eq!(last.check_region(Region::Etsi), Ok(()));
+        assert_eq!(last.check_region(Region::Fcc), Ok(()));
+        let above = Channel::new(84).unwrap();
+        assert_eq!(
+            above.check_region(Region::Etsi),
+            Err(Error::NotAllowed {
+                channel: above,
+                region: Region::Etsi
+            })
+        );
+
+        let custom = Region::Custom {
+            min_mhz: 2410,
+            max_mhz: 2470,
+        };
+        assert!(Channel::new(9).unwrap().check_region(custom).is_err());
+        assert!(Channel::new(10).unwrap().check_region(custom).is_ok());
+        assert!(Channel::new(70).unwrap().check_region(custom).is_ok());
+        assert!(Channel::new(71).unwrap().check_region(custom).is_err());
+    }
+
+    #[test]
+    fn test_spacing() {
+        let a = Channel::new(40).unwrap();
+        let b = Channel::new(41).unwrap();
+        let c = Channel::new(42).unwrap();
+        assert_eq!(
+            check_spacing(a, a, fields::DataRate::Kbps250),
+            Err(Error::TooClose { a, b: a })
+        );
+        assert_eq!(check_spacing(a, b, fields::DataRate::Mbps1), Ok(()));
+        assert_eq!(
+            check_spacing(b, a, fields::DataRate::Mbps2),
+            Err(Error::TooClose { a: b, b: a })
+        );
+        assert_eq!(check_spacing(a, c, fields::DataRate::Mbps2), Ok(()));
+    }
+}
@@ -16,14 +16,240 @@
 //! let spi_bytes = write_command.bytes();
 //! assert_eq!(spi_bytes, [0b0010_0000, 0b0100_0110]);
 //! ```
-use crate::registers::{self, AddressRegister, Register};
+//!
+//! ## Example with a single DMA buffer
+//! Commands can be encoded into a caller's buffer and their response decoded in place after a
+//! full-duplex transfer.
+//! ```rust
+//! use nrf24l01_commands::commands;
+//!
+//! static mut DMA_BUF: [u8; commands::MAX_SPI_BYTES] = [0; commands::MAX_SPI_BYTES];
+//! let buf = unsafe { &mut *core::ptr::addr_of_mut!(DMA_BUF) };
+//!
+//! let len = commands::RRxPayload::<4>::encode_into(buf).unwrap();
+//! assert_eq!(&buf[..len], &[0b0110_0001, 0, 0, 0, 0]);
+//!
+//! // The transfer replaces the buffer with the bytes shifted out on MISO
+//! buf[..len].copy_from_slice(&[0b0100_0000, 1, 2, 3, 4]);
+//! let response = commands::RRxPayload::<4>::decode_ref(buf).unwrap();
+//! assert!(response.status.rx_dr());
+//! assert_eq!(response.payload, &[1, 2, 3, 4]);
+//! ```
+use crate::fields;
+use crate::registers::{self, AddressRegister, Register, WritableRegister};
 use core::marker::PhantomData;
 
-/// A trait for nRF24L01+ commands. Defines the command's _command word_.
-#[const_trait]
+/// A trait for nRF24L01+ commands. Defines the command's _command word_
+/// and the type of its response.
+#[cfg_attr(feature = "nightly", const_trait)]
 pub trait Command {
     /// Command word.
     const WORD: u8;
+    /// Response decoded from the bytes shifted out on MISO.
+    type Response;
+}
+
+/// Response to [`R_REGISTER`][RRegister].
+///
+/// #### Type Parameter `R`
+/// Register type.
+#[derive(Copy, Clone, Debug)]
+pub struct RegisterResponse<R> {
+    /// Status register shifted out while the command word is sent.
+    pub status: registers::Status,
+    /// Register that was read.
+    pub register: R,
+}
+
+/// Response to [`R_RX_PAYLOAD`][RRxPayload].
+///
+/// #### Const Parameter `N`
+/// Width of RX payload.
+#[derive(Copy, Clone, Debug)]
+pub struct RxPayloadResponse<const N: usize> {
+    /// Status register shifted out while the command word is sent.
+    pub status: registers::Status,
+    /// Payload that was read.
+    pub payload: [u8; N],
+}
+
+/// Response to [`R_RX_PL_WID`][RRxPlWid].
+#[derive(Copy, Clone, Debug)]
+pub struct RxPlWidResponse {
+    /// Status register shifted out while the command word is sent.
+    pub status: registers::Status,
+    /// Width of the top payload in RX FIFO.
+    pub width: u8,
+}
+
+/// Response to [`R_RX_PAYLOAD`][RRxPayload], borrowing the payload from the buffer it was
+/// decoded from.
+#[derive(Copy, Clone, Debug)]
+pub struct RxPayloadRef<'a> {
+    /// Status register shifted out while the command word is sent.
+    pub status: registers::Status,
+    /// Payload that was read.
+    pub payload: &'a [u8],
+}
+
+/// Error encoding a command into a buffer or decoding its response from a buffer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// Buffer is shorter than the command's SPI byte sequence.
+    BufferTooShort {
+        /// Length of the SPI byte sequence.
+        required: usize,
+        /// Length of the buffer.
+        len: usize,
+    },
+    /// Payload width is not 1 to 32 bytes.
+    InvalidPayloadWidth {
+        /// Payload width.
+        width: usize,
+    },
+}
+
+/// Payload width `N` of a command, checked at compile time.
+pub(crate) struct PayloadWidth<const N: usize>;
+
+impl<const N: usize> PayloadWidth<N> {
+    /// Fails to compile, when used, if `N` is not 1 to 32.
+    pub(crate) const ASSERT: () = assert!(
+        N >= 1 && N <= MAX_PAYLOAD_WIDTH,
+        "payload width must be 1 to 32 bytes"
+    );
+}
+
+/// Check that a payload is 1 to [`MAX_PAYLOAD_WIDTH`] bytes.
+const fn check_payload_width(width: usize) -> Result<(), Error> {
+    if width == 0 || width > MAX_PAYLOAD_WIDTH {
+        return Err(Error::InvalidPayloadWidth { width });
+    }
+    Ok(())
+}
+
+/// Check that `buf` can hold an SPI byte sequence of length `required`.
+const fn check_len(buf: &[u8], required: usize) -> Result<(), Error> {
+    if buf.len() < required {
+        return Err(Error::BufferTooShort {
+            required,
+            len: buf.len(),
+        });
+    }
+    Ok(())
+}
+
+/// Write the command word followed by `bytes` to the start of `buf`.
+/// Returns the length of the SPI byte sequence.
+const fn encode_word_then(word: u8, bytes: &[u8], buf: &mut [u8]) -> Result<usize, Error> {
+    if let Err(e) = check_len(buf, bytes.len() + 1) {
+        return Err(e);
+    }
+    buf[0] = word;
+    let mut i = 0;
+    while i < bytes.len() {
+        buf[i + 1] = bytes[i];
+        i += 1;
+    }
+    Ok(bytes.len() + 1)
+}
+
+/// Write the command word followed by `len` zero bytes, to be replaced by the bytes read on MISO,
+/// to the start of `buf`. Returns the length of the SPI byte sequence.
+const fn encode_word_read(word: u8, len: usize, buf: &mut [u8]) -> Result<usize, Error> {
+    if let Err(e) = check_len(buf, len + 1) {
+        return Err(e);
+    }
+    buf[0] = word;
+    let mut i = 1;
+    while i < len + 1 {
+        buf[i] = 0;
+        i += 1;
+    }
+    Ok(len + 1)
+}
+
+/// Decode the status register from `buf` after a command of length `required`.
+const fn decode_status(buf: &[u8], required: usize) -> Result<registers::Status, Error> {
+    if let Err(e) = check_len(buf, required) {
+        return Err(e);
+    }
+    Ok(registers::Status::from_bits(buf[0]))
+}
+
+/// Maximum width of a payload.
+pub const MAX_PAYLOAD_WIDTH: usize = 32;
+
+/// Maximum length of a command's SPI byte sequence: the command word and a 32 byte payload.
+pub const MAX_SPI_BYTES: usize = MAX_PAYLOAD_WIDTH + 1;
+
+/// SPI byte sequence of a command, with a length known at runtime.
+///
+/// Used where sequences of different commands are stored together.
+///
+/// ## Example
+/// ```rust
+/// use nrf24l01_commands::{commands, registers};
+///
+/// let bytes = commands::SpiBytes::new(&commands::WRegister(registers::RfCh::new()).bytes());
+/// assert_eq!(bytes.as_slice(), &[0b0010_0101, 2]);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SpiBytes {
+    bytes: [u8; MAX_SPI_BYTES],
+    len: usize,
+}
+
+impl SpiBytes {
+    /// Copy a byte sequence.
+    ///
+    /// # Panics
+    /// If `bytes` is longer than [`MAX_SPI_BYTES`].
+    pub const fn new(bytes: &[u8]) -> Self {
+        assert!(bytes.len() <= MAX_SPI_BYTES, "SPI byte sequence too long");
+        let mut buf = [0; MAX_SPI_BYTES];
+        let mut i = 0;
+        while i < bytes.len() {
+            buf[i] = bytes[i];
+            i += 1;
+        }
+        Self {
+            bytes: buf,
+            len: bytes.len(),
+        }
+    }
+
+    /// Concatenate a command word and the following bytes.
+    pub(crate) const fn with_word(word: u8, bytes: &[u8]) -> Self {
+        let mut spi_bytes = Self::new(&[word]);
+        let mut i = 0;
+        while i < bytes.len() {
+            spi_bytes.bytes[i + 1] = bytes[i];
+            i += 1;
+        }
+        spi_bytes.len += bytes.len();
+        spi_bytes
+    }
+
+    /// The byte sequence.
+    pub const fn as_slice(&self) -> &[u8] {
+        self.bytes.split_at(self.len).0
+    }
+
+    /// The byte sequence, mutable for an in-place SPI transfer.
+    pub const fn as_mut_slice(&mut self) -> &mut [u8] {
+        self.bytes.split_at_mut(self.len).0
+    }
+
+    /// Length of the byte sequence.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the byte sequence is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
 }
 
 /// # R_REGISTER command
@@ -33,12 +259,23 @@ pub trait Command {
 /// Register type.
 ///
 /// ## Example
-/// ```rust
+#[cfg_attr(feature = "nightly", doc = "```rust")]
+#[cfg_attr(not(feature = "nightly"), doc = "```rust,ignore")]
 /// use nrf24l01_commands::{registers, registers::AddressRegister, commands};
 ///
 /// // Generate SPI byte sequence for R_REGISTER on FIFO_STATUS register.
 /// let bytes = commands::RRegister::<registers::FifoStatus>::bytes();
 /// assert_eq!(bytes, [0 | 0x17, 0]);
+///
+/// // Parse the bytes shifted out on MISO.
+/// let response = commands::RRegister::<registers::FifoStatus>::parse(&[0b0000_1110, 0b0001_0001]);
+/// assert_eq!(response.status.into_bits(), 0b0000_1110);
+/// assert!(response.register.tx_empty());
+/// assert!(response.register.rx_empty());
+///
+/// // Parse the response of R_REGISTER on TX_ADDR register.
+/// let response = commands::RRegister::<registers::TxAddr<5>>::parse(&[0b0000_1110, 0x0B, 0x32, 0x7C, 0xDE, 0x61]);
+/// assert_eq!(response.register.into_bits(), 0x61DE7C320B);
 /// ```
 pub struct RRegister<R>(PhantomData<R>);
 
@@ -46,7 +283,8 @@ pub struct RRegister<R>(PhantomData<R>);
 /// Write a register.
 ///
 /// ## Example
-/// ```rust
+#[cfg_attr(feature = "nightly", doc = "```rust")]
+#[cfg_attr(not(feature = "nightly"), doc = "```rust,ignore")]
 /// use nrf24l01_commands::{registers, registers::AddressRegister, commands};
 ///
 /// // Generate SPI byte sequence for W_REGISTER on RF_CH register.
@@ -58,6 +296,19 @@ pub struct RRegister<R>(PhantomData<R>);
 /// let tx_addr = registers::TxAddr::<5>::new().with_tx_addr(0x61DE7C320B);
 /// let bytes = commands::WRegister(tx_addr).bytes();
 /// assert_eq!(bytes, [0b0010_0000 | 0x10, 0x0B, 0x32, 0x7C, 0xDE, 0x61]);
+///
+/// // Read-only fields are not written.
+/// let status = registers::Status::from_bits(0b0100_1111);
+/// let bytes = commands::WRegister(status).bytes();
+/// assert_eq!(bytes, [0b0010_0000 | 0x07, 0b0100_0000]);
+/// ```
+///
+/// Read-only registers can't be written.
+/// ```compile_fail,E0599
+/// use nrf24l01_commands::{registers, commands};
+///
+/// let bytes = commands::WRegister(registers::RfCh::new()).bytes();
+/// let bytes = commands::WRegister(registers::FifoStatus::new()).bytes();
 /// ```
 pub struct WRegister<R>(
     /// Register to write.
@@ -68,14 +319,11 @@ pub struct WRegister<R>(
 /// Read RX payload.
 ///
 /// #### Const Parameter `N`
-/// Width of RX payload.
-///
-/// <div class="warning">
-/// Must be 1 to 32 bytes.
-/// </div>
+/// Width of RX payload. Must be 1 to 32 bytes, otherwise using the command fails to compile.
 ///
 /// ## Example
-/// ```rust
+#[cfg_attr(feature = "nightly", doc = "```rust")]
+#[cfg_attr(not(feature = "nightly"), doc = "```rust,ignore")]
 /// #![feature(generic_const_exprs)] // TODO: https://github.com/rust-lang/rust/issues/133199#issuecomment-2630615573
 /// use nrf24l01_commands::commands;
 ///
@@ -84,6 +332,19 @@ pub struct WRegister<R>(
 /// let mut expected_bytes = [0; 18];
 /// expected_bytes[0] = 0b0110_0001;
 /// assert_eq!(bytes, expected_bytes);
+///
+/// // Parse the bytes shifted out on MISO.
+/// let response = commands::RRxPayload::<4>::parse(&[0b0100_0010, 1, 2, 3, 4]);
+/// assert!(response.status.rx_dr());
+/// assert_eq!(response.payload, [1, 2, 3, 4]);
+/// ```
+///
+/// An empty payload fails to compile.
+/// ```rust,compile_fail,E0080
+/// use nrf24l01_commands::commands;
+///
+/// let bytes = commands::RRxPayload::<32>::spi_bytes();
+/// let bytes = commands::RRxPayload::<0>::spi_bytes();
 /// ```
 pub struct RRxPayload<const N: usize>();
 
@@ -91,18 +352,26 @@ pub struct RRxPayload<const N: usize>();
 /// Write TX payload. Payload byte-order is kept as MSByte first contrary to documentation.
 ///
 /// ## Example
-/// ```rust
+#[cfg_attr(feature = "nightly", doc = "```rust")]
+#[cfg_attr(not(feature = "nightly"), doc = "```rust,ignore")]
 /// use nrf24l01_commands::commands;
 ///
 /// let payload = [1, 2, 3, 4, 5, 6, 7, 8, 9];
 /// let bytes = commands::WTxPayload(payload).bytes();
 /// assert_eq!(bytes, [0b1010_0000, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
 /// ```
+///
+/// A payload longer than 32 bytes fails to compile.
+/// ```rust,compile_fail,E0080
+/// use nrf24l01_commands::commands;
+///
+/// let bytes = commands::WTxPayload([0; 32]).spi_bytes();
+/// let bytes = commands::WTxPayload([0; 33]).spi_bytes();
+/// ```
 pub struct WTxPayload<const N: usize>(
     /// Payload to write.
-    /// <div class="warning">
-    /// Payload must be 1 to 32 bytes.
-    /// </div>
+    ///
+    /// Must be 1 to 32 bytes, otherwise using the command fails to compile.
     pub [u8; N],
 );
 
@@ -153,6 +422,10 @@ pub struct ReuseTxPl();
 ///
 /// let bytes = commands::RRxPlWid::bytes();
 /// assert_eq!(bytes, [0b0110_0000, 0]);
+///
+/// // Parse the bytes shifted out on MISO.
+/// let response = commands::RRxPlWid::parse(&[0b0100_0000, 17]);
+/// assert_eq!(response.width, 17);
 /// ```
 pub struct RRxPlWid();
 
@@ -162,21 +435,21 @@ pub struct RRxPlWid();
 /// are handled first-in-first-out. Payload byte-order is kept as MSByte first contrary to documentation.
 ///
 /// ## Example
-/// ```rust
-/// use nrf24l01_commands::commands;
+#[cfg_attr(feature = "nightly", doc = "```rust")]
+#[cfg_attr(not(feature = "nightly"), doc = "```rust,ignore")]
+/// use nrf24l01_commands::{commands, fields};
 ///
-/// let pipe = 4;
+/// let pipe = fields::Pipe::P4;
 /// let payload = [1, 2, 3, 4, 5, 6, 7, 8, 9];
 /// let bytes = commands::WAckPayload { pipe, payload }.bytes();
-/// assert_eq!(bytes, [0b1010_1000 | pipe, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+/// assert_eq!(bytes, [0b1010_1000 | 4, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
 /// ```
 pub struct WAckPayload<const N: usize> {
     /// Data pipe this ACK payload is designated to.
-    pub pipe: u8,
+    pub pipe: fields::Pipe,
     /// Payload to send with ACK.
-    /// <div class="warning">
-    /// Payload must be 1 to 32 bytes.
-    /// </div>
+    ///
+    /// Must be 1 to 32 bytes, otherwise using the command fails to compile.
     pub payload: [u8; N],
 }
 
@@ -184,7 +457,8 @@ pub struct WAckPayload<const N: usize> {
 /// Write TX payload with AUTOACK disabled. Payload byte-order is kept as MSByte first contrary to documentation.
 ///
 /// ## Example
-/// ```rust
+#[cfg_attr(feature = "nightly", doc = "```rust")]
+#[cfg_attr(not(feature = "nightly"), doc = "```rust,ignore")]
 /// use nrf24l01_commands::commands;
 ///
 /// let payload = [1, 2, 3, 4, 5, 6, 7, 8, 9];
@@ -193,9 +467,8 @@ pub struct WAckPayload<const N: usize> {
 /// ```
 pub struct WTxPayloadNoack<const N: usize>(
     /// Payload to write.
-    /// <div class="warning">
-    /// Payload must be 1 to 32 bytes.
-    /// </div>
+    ///
+    /// Must be 1 to 32 bytes, otherwise using the command fails to compile.
     pub [u8; N],
 );
 
@@ -208,110 +481,381 @@ pub struct WTxPayloadNoack<const N: usize>(
 ///
 /// assert_eq!(commands::Nop::WORD, 0xFF);
 /// assert_eq!(commands::Nop::bytes(), [0xFF]);
+///
+/// // Parse the status register shifted out on MISO.
+/// let status = commands::Nop::parse(&[0b0010_1110]);
+/// assert!(status.tx_ds());
 /// ```
 pub struct Nop();
 
-impl<R> const Command for RRegister<R> {
-    const WORD: u8 = 0;
+/// # W_REGISTER command clearing interrupt flags
+/// Write 1 to the chosen interrupt flags of the [`STATUS`][registers::Status] register.
+/// Flags that are not chosen are written 0 and left untouched, and the read-only `rx_p_no` and
+/// `tx_full` bits are never written.
+///
+/// Unlike writing back a status that was just read with [`WRegister`], flags that were set
+/// meanwhile and have not been handled yet stay set.
+///
+/// ## Example
+/// ```rust
+/// use nrf24l01_commands::{commands, fields::Interrupt};
+///
+/// let bytes = commands::ClearInterrupts::only(Interrupt::TxDs).bytes();
+/// assert_eq!(bytes, [0b0010_0111, 0b0010_0000]);
+///
+/// // Clear the flags of a status that was read
+/// let status = commands::Nop::parse(&[0b0101_1110]);
+/// let command = commands::ClearInterrupts::from_interrupts(status.interrupts());
+/// assert_eq!(command.bytes(), [0b0010_0111, 0b0101_0000]);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ClearInterrupts {
+    /// Clear `rx_dr`.
+    pub rx_dr: bool,
+    /// Clear `tx_ds`.
+    pub tx_ds: bool,
+    /// Clear `max_rt`.
+    pub max_rt: bool,
+}
+
+/// # R_RX_PAYLOAD command with a payload width known at runtime
+/// Read RX payload into a slice, e.g. of the width given by [`R_RX_PL_WID`][RRxPlWid] with
+/// dynamic payload length.
+///
+/// ## Example
+/// ```rust
+/// use nrf24l01_commands::commands;
+///
+/// let mut payload = [0; 32];
+/// let width = commands::RRxPlWid::parse(&[0b0100_0000, 3]).width;
+/// let command = commands::RRxPayloadSlice::new(&mut payload[..width as usize]).unwrap();
+/// assert_eq!(command.spi_bytes().as_slice(), &[0b0110_0001, 0, 0, 0]);
+///
+/// // Decode the bytes shifted out on MISO into the slice
+/// let status = command.decode(&[0b0100_0000, 1, 2, 3]).unwrap();
+/// assert!(status.rx_dr());
+/// assert_eq!(payload[..3], [1, 2, 3]);
+///
+/// // Width must be 1 to 32 bytes
+/// assert!(commands::RRxPayloadSlice::new(&mut []).is_err());
+/// ```
+pub struct RRxPayloadSlice<'a>(&'a mut [u8]);
+
+/// # W_TX_PAYLOAD command with a payload width known at runtime
+/// Write TX payload. Payload byte-order is kept as MSByte first contrary to documentation.
+///
+/// ## Example
+/// ```rust
+/// use nrf24l01_commands::commands;
+///
+/// let command = commands::WTxPayloadSlice::new(&[1, 2, 3]).unwrap();
+/// assert_eq!(command.spi_bytes().as_slice(), &[0b1010_0000, 1, 2, 3]);
+///
+/// // Payload must be 1 to 32 bytes
+/// assert_eq!(
+///     commands::WTxPayloadSlice::new(&[0; 33]).unwrap_err(),
+///     commands::Error::InvalidPayloadWidth { width: 33 }
+/// );
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct WTxPayloadSlice<'a>(&'a [u8]);
+
+/// # W_ACK_PAYLOAD command with a payload width known at runtime
+/// Write payload to be transmitted with ACK packet on a data pipe. Used in RX mode.
+/// Payload byte-order is kept as MSByte first contrary to documentation.
+///
+/// ## Example
+/// ```rust
+/// use nrf24l01_commands::{commands, fields};
+///
+/// let command = commands::WAckPayloadSlice::new(fields::Pipe::P4, &[1, 2, 3]).unwrap();
+/// assert_eq!(command.spi_bytes().as_slice(), &[0b1010_1000 | 4, 1, 2, 3]);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct WAckPayloadSlice<'a> {
+    pipe: fields::Pipe,
+    payload: &'a [u8],
 }
-impl<R> const Command for WRegister<R> {
-    const WORD: u8 = 0b0010_0000;
+
+/// # W_TX_PAYLOAD_NOACK command with a payload width known at runtime
+/// Write TX payload with AUTOACK disabled. Payload byte-order is kept as MSByte first contrary to documentation.
+///
+/// ## Example
+/// ```rust
+/// use nrf24l01_commands::commands;
+///
+/// let command = commands::WTxPayloadNoackSlice::new(&[1, 2, 3]).unwrap();
+/// assert_eq!(command.spi_bytes().as_slice(), &[0b1011_0000, 1, 2, 3]);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct WTxPayloadNoackSlice<'a>(&'a [u8]);
+
+const_impl! {
+    impl<R> Command for RRegister<R> {
+        const WORD: u8 = 0;
+        type Response = RegisterResponse<R>;
+    }
 }
-impl<const N: usize> const Command for RRxPayload<N> {
-    const WORD: u8 = 0b0110_0001;
+const_impl! {
+    impl<R> Command for WRegister<R> {
+        const WORD: u8 = 0b0010_0000;
+        type Response = registers::Status;
+    }
 }
-impl<const N: usize> const Command for WTxPayload<N> {
-    const WORD: u8 = 0b1010_0000;
+const_impl! {
+    impl<const N: usize> Command for RRxPayload<N> {
+        const WORD: u8 = 0b0110_0001;
+        type Response = RxPayloadResponse<N>;
+    }
 }
-impl const Command for FlushTx {
-    const WORD: u8 = 0b1110_0001;
+const_impl! {
+    impl<const N: usize> Command for WTxPayload<N> {
+        const WORD: u8 = 0b1010_0000;
+        type Response = registers::Status;
+    }
 }
-impl const Command for FlushRx {
-    const WORD: u8 = 0b1110_0010;
+const_impl! {
+    impl Command for FlushTx {
+        const WORD: u8 = 0b1110_0001;
+        type Response = registers::Status;
+    }
 }
-impl const Command for ReuseTxPl {
-    const WORD: u8 = 0b1110_0011;
+const_impl! {
+    impl Command for FlushRx {
+        const WORD: u8 = 0b1110_0010;
+        type Response = registers::Status;
+    }
 }
-impl const Command for RRxPlWid {
-    const WORD: u8 = 0b0110_0000;
+const_impl! {
+    impl Command for ReuseTxPl {
+        const WORD: u8 = 0b1110_0011;
+        type Response = registers::Status;
+    }
 }
-impl<const N: usize> const Command for WAckPayload<N> {
-    const WORD: u8 = 0b1010_1000;
+const_impl! {
+    impl Command for RRxPlWid {
+        const WORD: u8 = 0b0110_0000;
+        type Response = RxPlWidResponse;
+    }
 }
-impl<const N: usize> const Command for WTxPayloadNoack<N> {
-    const WORD: u8 = 0b1011_0000;
+const_impl! {
+    impl<const N: usize> Command for WAckPayload<N> {
+        const WORD: u8 = 0b1010_1000;
+        type Response = registers::Status;
+    }
 }
-impl const Command for Nop {
-    const WORD: u8 = 0b1111_1111;
+const_impl! {
+    impl<const N: usize> Command for WTxPayloadNoack<N> {
+        const WORD: u8 = 0b1011_0000;
+        type Response = registers::Status;
+    }
 }
-
-impl<R: const Register> RRegister<R> {
-    /// Get the command's _command word_.
-    pub const fn word() -> u8 {
-        Self::WORD | R::ADDRESS
+const_impl! {
+    impl Command for Nop {
+        const WORD: u8 = 0b1111_1111;
+        type Response = registers::Status;
     }
-
-    /// Generate the command's SPI byte sequence.
-    pub const fn bytes() -> [u8; 2] {
-        [Self::word(), 0]
+}
+const_impl! {
+    impl Command for ClearInterrupts {
+        const WORD: u8 = 0b0010_0000 | 0x07;
+        type Response = registers::Status;
     }
 }
-
-impl<const N: usize> RRegister<registers::RxAddrP0<N>> {
-    /// Get the command's _command word_.
-    pub const fn word() -> u8 {
-        Self::WORD | registers::RxAddrP0::<N>::ADDRESS
+const_impl! {
+    impl<'a> Command for RRxPayloadSlice<'a> {
+        const WORD: u8 = RRxPayload::<1>::WORD;
+        type Response = registers::Status;
     }
-
-    /// Generate the command's SPI byte sequence.
-    pub const fn bytes() -> [u8; N + 1] {
-        let mut bytes = [0; N + 1];
-        bytes[0] = Self::word();
-        bytes
+}
+const_impl! {
+    impl<'a> Command for WTxPayloadSlice<'a> {
+        const WORD: u8 = WTxPayload::<1>::WORD;
+        type Response = registers::Status;
     }
 }
-
-impl<const N: usize> RRegister<registers::RxAddrP1<N>> {
-    /// Get the command's _command word_.
-    pub const fn word() -> u8 {
-        Self::WORD | registers::RxAddrP1::<N>::ADDRESS
+const_impl! {
+    impl<'a> Command for WAckPayloadSlice<'a> {
+        const WORD: u8 = WAckPayload::<1>::WORD;
+        type Response = registers::Status;
     }
-
-    /// Generate the command's SPI byte sequence.
-    pub const fn bytes() -> [u8; N + 1] {
-        let mut bytes = [0; N + 1];
-        bytes[0] = Self::word();
-        bytes
+}
+const_impl! {
+    impl<'a> Command for WTxPayloadNoackSlice<'a> {
+        const WORD: u8 = WTxPayloadNoack::<1>::WORD;
+        type Response = registers::Status;
     }
 }
 
-impl<const N: usize> RRegister<registers::TxAddr<N>> {
-    /// Get the command's _command word_.
-    pub const fn word() -> u8 {
-        Self::WORD | registers::TxAddr::<N>::ADDRESS
+impl<R> RRegister<R> {
+    /// Create the command.
+    pub const fn new() -> Self {
+        Self(PhantomData)
     }
+}
 
-    /// Generate the command's SPI byte sequence.
-    pub const fn bytes() -> [u8; N + 1] {
-        let mut bytes = [0; N + 1];
-        bytes[0] = Self::word();
-        bytes
+impl<R> Default for RRegister<R> {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
-impl<R: const Register> WRegister<R> {
-    /// Get the command's _command word_.
-    pub const fn word() -> u8 {
-        Self::WORD | R::ADDRESS
-    }
+/// Implement [`R_REGISTER`][RRegister] on single byte registers.
+///
+/// With the `nightly` feature this is a blanket impl over `R: const Register`. On stable, trait
+/// methods can't be called in `const fn`, so each register gets its own impl which resolves to
+/// the register's inherent `const fn`s.
+macro_rules! impl_read_register_commands {
+    ([$($generics:tt)*]) => {};
+    ([$($generics:tt)*] $reg:ty $(, $rest:ty)*) => {
+        impl<$($generics)*> RRegister<$reg> {
+            /// Get the command's _command word_.
+            pub const fn word() -> u8 {
+                Self::WORD | <$reg as Register>::ADDRESS
+            }
 
-    /// Generate the command's SPI byte sequence.
-    pub const fn bytes(&self) -> [u8; 2] {
-        [Self::word(), self.0.into_bits()]
+            /// Generate the command's SPI byte sequence.
+            pub const fn bytes() -> [u8; 2] {
+                [Self::word(), 0]
+            }
+
+            /// Parse the bytes shifted out on MISO during the command.
+            pub const fn parse(bytes: &[u8; 2]) -> RegisterResponse<$reg> {
+                RegisterResponse {
+                    status: registers::Status::from_bits(bytes[0]),
+                    register: <$reg>::from_bits(bytes[1]),
+                }
+            }
+
+            /// Write the command's SPI byte sequence to the start of `buf` and return its length.
+            pub const fn encode_into(buf: &mut [u8]) -> Result<usize, Error> {
+                encode_word_read(Self::word(), 1, buf)
+            }
+
+            /// Decode the bytes shifted out on MISO during the command from the start of `buf`.
+            pub const fn decode(buf: &[u8]) -> Result<RegisterResponse<$reg>, Error> {
+                if let Err(e) = check_len(buf, 2) {
+                    return Err(e);
+                }
+                Ok(Self::parse(&[buf[0], buf[1]]))
+            }
+        }
+
+        impl_read_register_commands!([$($generics)*] $($rest),*);
+    };
+}
+
+/// Implement [`W_REGISTER`][WRegister] on writable single byte registers, like
+/// [`impl_read_register_commands`].
+macro_rules! impl_write_register_commands {
+    ([$($generics:tt)*]) => {};
+    ([$($generics:tt)*] $reg:ty $(, $rest:ty)*) => {
+        impl<$($generics)*> WRegister<$reg> {
+            /// Get the command's _command word_.
+            pub const fn word() -> u8 {
+                Self::WORD | <$reg as Register>::ADDRESS
+            }
+
+            /// Generate the command's SPI byte sequence. Read-only bits of the register are
+            /// cleared.
+            pub const fn bytes(&self) -> [u8; 2] {
+                [Self::word(), (self.0.into_bits() & <$reg as WritableRegister>::WRITE_MASK)]
+            }
+
+            /// Parse the bytes shifted out on MISO during the command.
+            pub const fn parse(bytes: &[u8; 2]) -> registers::Status {
+                registers::Status::from_bits(bytes[0])
+            }
+
+            /// Write the command's SPI byte sequence to the start of `buf` and return its length.
+            pub const fn encode_into(&self, buf: &mut [u8]) -> Result<usize, Error> {
+                encode_word_then(Self::word(), &[(self.0.into_bits() & <$reg as WritableRegister>::WRITE_MASK)], buf)
+            }
+
+            /// Decode the bytes shifted out on MISO during the command from the start of `buf`.
+            pub const fn decode(buf: &[u8]) -> Result<registers::Status, Error> {
+                decode_status(buf, 2)
+            }
+        }
+
+        impl_write_register_commands!([$($generics)*] $($rest),*);
+    };
+}
+
+#[cfg(feature = "nightly")]
+impl_read_register_commands!([R: const Register + registers::ReadableRegister] R);
+
+#[cfg(feature = "nightly")]
+impl_write_register_commands!([R: const Register + WritableRegister] R);
+
+#[cfg(not(feature = "nightly"))]
+impl_read_register_commands!(
+    []
+    registers::Config,
+    registers::EnAa,
+    registers::EnRxaddr,
+    registers::SetupAw,
+    registers::SetupRetr,
+    registers::RfCh,
+    registers::RfSetup,
+    registers::Status,
+    registers::ObserveTx,
+    registers::Rpd,
+    registers::RxAddrP2,
+    registers::RxAddrP3,
+    registers::RxAddrP4,
+    registers::RxAddrP5,
+    registers::RxPwP0,
+    registers::RxPwP1,
+    registers::RxPwP2,
+    registers::RxPwP3,
+    registers::RxPwP4,
+    registers::RxPwP5,
+    registers::FifoStatus,
+    registers::Dynpd,
+    registers::Feature
+);
+
+#[cfg(not(feature = "nightly"))]
+impl_write_register_commands!(
+    []
+    registers::Config,
+    registers::EnAa,
+    registers::EnRxaddr,
+    registers::SetupAw,
+    registers::SetupRetr,
+    registers::RfCh,
+    registers::RfSetup,
+    registers::Status,
+    registers::RxAddrP2,
+    registers::RxAddrP3,
+    registers::RxAddrP4,
+    registers::RxAddrP5,
+    registers::RxPwP0,
+    registers::RxPwP1,
+    registers::RxPwP2,
+    registers::RxPwP3,
+    registers::RxPwP4,
+    registers::RxPwP5,
+    registers::Dynpd,
+    registers::Feature
+);
+
+/// Split the status byte from the address bytes.
+#[inline(always)]
+const fn split_status_addr<const N: usize>(bytes: &[u8]) -> (registers::Status, [u8; N]) {
+    let mut addr = [0; N];
+    // Addr is already in little-endian byte-order
+    let mut i = 0;
+    while i < N {
+        addr[i] = bytes[i + 1];
+        i += 1;
     }
+    (registers::Status::from_bits(bytes[0]), addr)
 }
 
 /// Concatenate the command word and address bytes into an array.
+#[cfg(feature = "nightly")]
 #[inline(always)]
 const fn concat_word_addr<const N: usize>(word: u8, addr: [u8; N]) -> [u8; N + 1] {
     let mut bytes: [u8; N + 1] = [0; N + 1];
@@ -325,52 +869,183 @@ const fn concat_word_addr<const N: usize>(word: u8, addr: [u8; N]) -> [u8; N + 1
     bytes
 }
 
-impl<const N: usize> WRegister<registers::RxAddrP0<N>> {
-    /// Get the command's _command word_.
-    pub const fn word() -> u8 {
-        Self::WORD | registers::RxAddrP0::<N>::ADDRESS
-    }
+/// Implement [`R_REGISTER`][RRegister] and [`W_REGISTER`][WRegister] on an address register.
+macro_rules! impl_address_register_commands {
+    ($reg:ident) => {
+        impl<const N: usize> RRegister<registers::$reg<N>> {
+            /// Get the command's _command word_.
+            pub const fn word() -> u8 {
+                Self::WORD | registers::$reg::<N>::ADDRESS
+            }
 
-    /// Generate the command's SPI byte sequence.
-    pub const fn bytes(&self) -> [u8; N + 1] {
-        concat_word_addr(Self::word(), self.0.into_bytes())
-    }
+            /// Generate the command's SPI byte sequence in a fixed size buffer.
+            pub const fn spi_bytes() -> SpiBytes {
+                SpiBytes::with_word(Self::word(), &[0; N])
+            }
+
+            /// Parse the bytes shifted out on MISO during the command.
+            ///
+            /// # Panics
+            /// If `bytes` is shorter than `N + 1`.
+            pub const fn parse_slice(bytes: &[u8]) -> RegisterResponse<registers::$reg<N>> {
+                let (status, addr) = split_status_addr(bytes);
+                RegisterResponse {
+                    status,
+                    register: registers::$reg::<N>::from_bytes(addr),
+                }
+            }
+
+            /// Write the command's SPI byte sequence to the start of `buf` and return its length.
+            pub const fn encode_into(buf: &mut [u8]) -> Result<usize, Error> {
+                encode_word_read(Self::word(), N, buf)
+            }
+
+            /// Decode the bytes shifted out on MISO during the command from the start of `buf`.
+            pub const fn decode(buf: &[u8]) -> Result<RegisterResponse<registers::$reg<N>>, Error> {
+                if let Err(e) = check_len(buf, N + 1) {
+                    return Err(e);
+                }
+                Ok(Self::parse_slice(buf))
+            }
+        }
+
+        #[cfg(feature = "nightly")]
+        impl<const N: usize> RRegister<registers::$reg<N>> {
+            /// Generate the command's SPI byte sequence.
+            pub const fn bytes() -> [u8; N + 1] {
+                let mut bytes = [0; N + 1];
+                bytes[0] = Self::word();
+                bytes
+            }
+
+            /// Parse the bytes shifted out on MISO during the command.
+            pub const fn parse(bytes: &[u8; N + 1]) -> RegisterResponse<registers::$reg<N>> {
+                Self::parse_slice(bytes)
+            }
+        }
+
+        impl<const N: usize> WRegister<registers::$reg<N>> {
+            /// Get the command's _command word_.
+            pub const fn word() -> u8 {
+                Self::WORD | registers::$reg::<N>::ADDRESS
+            }
+
+            /// Generate the command's SPI byte sequence in a fixed size buffer.
+            pub const fn spi_bytes(&self) -> SpiBytes {
+                SpiBytes::with_word(Self::word(), &self.0.into_bytes())
+            }
+
+            /// Parse the bytes shifted out on MISO during the command.
+            ///
+            /// # Panics
+            /// If `bytes` is empty.
+            pub const fn parse_slice(bytes: &[u8]) -> registers::Status {
+                registers::Status::from_bits(bytes[0])
+            }
+
+            /// Write the command's SPI byte sequence to the start of `buf` and return its length.
+            pub const fn encode_into(&self, buf: &mut [u8]) -> Result<usize, Error> {
+                encode_word_then(Self::word(), &self.0.into_bytes(), buf)
+            }
+
+            /// Decode the bytes shifted out on MISO during the command from the start of `buf`.
+            pub const fn decode(buf: &[u8]) -> Result<registers::Status, Error> {
+                decode_status(buf, N + 1)
+            }
+        }
+
+        #[cfg(feature = "nightly")]
+        impl<const N: usize> WRegister<registers::$reg<N>> {
+            /// Generate the command's SPI byte sequence.
+            pub const fn bytes(&self) -> [u8; N + 1] {
+                concat_word_addr(Self::word(), self.0.into_bytes())
+            }
+
+            /// Parse the bytes shifted out on MISO during the command.
+            pub const fn parse(bytes: &[u8; N + 1]) -> registers::Status {
+                registers::Status::from_bits(bytes[0])
+            }
+        }
+    };
 }
 
-impl<const N: usize> WRegister<registers::RxAddrP1<N>> {
-    /// Get the command's _command word_.
-    pub const fn word() -> u8 {
-        Self::WORD | registers::RxAddrP1::<N>::ADDRESS
+impl_address_register_commands!(RxAddrP0);
+impl_address_register_commands!(RxAddrP1);
+impl_address_register_commands!(TxAddr);
+
+impl<const N: usize> RRxPayload<N> {
+    /// Generate the command's SPI byte sequence in a fixed size buffer.
+    pub const fn spi_bytes() -> SpiBytes {
+        let () = PayloadWidth::<N>::ASSERT;
+        SpiBytes::with_word(Self::WORD, &[0; N])
     }
 
-    /// Generate the command's SPI byte sequence.
-    pub const fn bytes(&self) -> [u8; N + 1] {
-        concat_word_addr(Self::word(), self.0.into_bytes())
+    /// Parse the bytes shifted out on MISO during the command.
+    ///
+    /// # Panics
+    /// If `bytes` is shorter than `N + 1`.
+    pub const fn parse_slice(bytes: &[u8]) -> RxPayloadResponse<N> {
+        let () = PayloadWidth::<N>::ASSERT;
+        let mut payload = [0; N];
+        let mut i = 0;
+        while i < N {
+            payload[i] = bytes[i + 1];
+            i += 1;
+        }
+        RxPayloadResponse {
+            status: registers::Status::from_bits(bytes[0]),
+            payload,
+        }
     }
-}
 
-impl<const N: usize> WRegister<registers::TxAddr<N>> {
-    /// Get the command's _command word_.
-    pub const fn word() -> u8 {
-        Self::WORD | registers::TxAddr::<N>::ADDRESS
+    /// Write the command's SPI byte sequence to the start of `buf` and return its length.
+    pub const fn encode_into(buf: &mut [u8]) -> Result<usize, Error> {
+        let () = PayloadWidth::<N>::ASSERT;
+        encode_word_read(Self::WORD, N, buf)
     }
 
-    /// Generate the command's SPI byte sequence.
-    pub const fn bytes(&self) -> [u8; N + 1] {
-        concat_word_addr(Self::word(), self.0.into_bytes())
+    /// Decode the bytes shifted out on MISO during the command from the start of `buf`.
+    pub const fn decode(buf: &[u8]) -> Result<RxPayloadResponse<N>, Error> {
+        let () = PayloadWidth::<N>::ASSERT;
+        if let Err(e) = check_len(buf, N + 1) {
+            return Err(e);
+        }
+        Ok(Self::parse_slice(buf))
+    }
+
+    /// Decode the bytes shifted out on MISO during the command from the start of `buf`,
+    /// borrowing the payload from `buf` instead of copying it.
+    pub const fn decode_ref(buf: &[u8]) -> Result<RxPayloadRef<'_>, Error> {
+        let () = PayloadWidth::<N>::ASSERT;
+        if let Err(e) = check_len(buf, N + 1) {
+            return Err(e);
+        }
+        Ok(RxPayloadRef {
+            status: registers::Status::from_bits(buf[0]),
+            payload: buf.split_at(N + 1).0.split_at(1).1,
+        })
     }
 }
 
+#[cfg(feature = "nightly")]
 impl<const N: usize> RRxPayload<N> {
     /// Generate the command's SPI byte sequence.
     pub const fn bytes() -> [u8; N + 1] {
+        let () = PayloadWidth::<N>::ASSERT;
         let mut bytes: [u8; N + 1] = [0; N + 1];
         bytes[0] = Self::WORD;
         bytes
     }
+
+    /// Parse the bytes shifted out on MISO during the command.
+    pub const fn parse(bytes: &[u8; N + 1]) -> RxPayloadResponse<N> {
+        let () = PayloadWidth::<N>::ASSERT;
+        Self::parse_slice(bytes)
+    }
 }
 
 /// Concatenate the command word and payload bytes into an array.
+#[cfg(feature = "nightly")]
 #[inline(always)]
 const fn concat_word_payload<const N: usize>(word: u8, payload: [u8; N]) -> [u8; N + 1] {
     let mut bytes: [u8; N + 1] = [0; N + 1];
@@ -384,11 +1059,48 @@ const fn concat_word_payload<const N: usize>(word: u8, payload: [u8; N]) -> [u8;
     bytes
 }
 
+impl<const N: usize> WTxPayload<N> {
+    /// Generate the command's SPI byte sequence in a fixed size buffer.
+    pub const fn spi_bytes(&self) -> SpiBytes {
+        let () = PayloadWidth::<N>::ASSERT;
+        SpiBytes::with_word(Self::WORD, &self.0)
+    }
+
+    /// Parse the bytes shifted out on MISO during the command.
+    ///
+    /// # Panics
+    /// If `bytes` is empty.
+    pub const fn parse_slice(bytes: &[u8]) -> registers::Status {
+        let () = PayloadWidth::<N>::ASSERT;
+        registers::Status::from_bits(bytes[0])
+    }
+
+    /// Write the command's SPI byte sequence to the start of `buf` and return its length.
+    pub const fn encode_into(&self, buf: &mut [u8]) -> Result<usize, Error> {
+        let () = PayloadWidth::<N>::ASSERT;
+        encode_word_then(Self::WORD, &self.0, buf)
+    }
+
+    /// Decode the bytes shifted out on MISO during the command from the start of `buf`.
+    pub const fn decode(buf: &[u8]) -> Result<registers::Status, Error> {
+        let () = PayloadWidth::<N>::ASSERT;
+        decode_status(buf, N + 1)
+    }
+}
+
+#[cfg(feature = "nightly")]
 impl<const N: usize> WTxPayload<N> {
     /// Generate the command's SPI byte sequence.
     pub const fn bytes(&self) -> [u8; N + 1] {
+        let () = PayloadWidth::<N>::ASSERT;
         concat_word_payload(Self::WORD, self.0)
     }
+
+    /// Parse the bytes shifted out on MISO during the command.
+    pub const fn parse(bytes: &[u8; N + 1]) -> registers::Status {
+        let () = PayloadWidth::<N>::ASSERT;
+        registers::Status::from_bits(bytes[0])
+    }
 }
 
 impl FlushTx {
@@ -396,6 +1108,21 @@ impl FlushTx {
     pub const fn bytes() -> [u8; 1] {
         [Self::WORD]
     }
+
+    /// Parse the bytes shifted out on MISO during the command.
+    pub const fn parse(bytes: &[u8; 1]) -> registers::Status {
+        registers::Status::from_bits(bytes[0])
+    }
+
+    /// Write the command's SPI byte sequence to the start of `buf` and return its length.
+    pub const fn encode_into(buf: &mut [u8]) -> Result<usize, Error> {
+        encode_word_then(Self::WORD, &[], buf)
+    }
+
+    /// Decode the bytes shifted out on MISO during the command from the start of `buf`.
+    pub const fn decode(buf: &[u8]) -> Result<registers::Status, Error> {
+        decode_status(buf, 1)
+    }
 }
 
 impl FlushRx {
@@ -403,6 +1130,21 @@ impl FlushRx {
     pub const fn bytes() -> [u8; 1] {
         [Self::WORD]
     }
+
+    /// Parse the bytes shifted out on MISO during the command.
+    pub const fn parse(bytes: &[u8; 1]) -> registers::Status {
+        registers::Status::from_bits(bytes[0])
+    }
+
+    /// Write the command's SPI byte sequence to the start of `buf` and return its length.
+    pub const fn encode_into(buf: &mut [u8]) -> Result<usize, Error> {
+        encode_word_then(Self::WORD, &[], buf)
+    }
+
+    /// Decode the bytes shifted out on MISO during the command from the start of `buf`.
+    pub const fn decode(buf: &[u8]) -> Result<registers::Status, Error> {
+        decode_status(buf, 1)
+    }
 }
 
 impl ReuseTxPl {
@@ -410,6 +1152,21 @@ impl ReuseTxPl {
     pub const fn bytes() -> [u8; 1] {
         [Self::WORD]
     }
+
+    /// Parse the bytes shifted out on MISO during the command.
+    pub const fn parse(bytes: &[u8; 1]) -> registers::Status {
+        registers::Status::from_bits(bytes[0])
+    }
+
+    /// Write the command's SPI byte sequence to the start of `buf` and return its length.
+    pub const fn encode_into(buf: &mut [u8]) -> Result<usize, Error> {
+        encode_word_then(Self::WORD, &[], buf)
+    }
+
+    /// Decode the bytes shifted out on MISO during the command from the start of `buf`.
+    pub const fn decode(buf: &[u8]) -> Result<registers::Status, Error> {
+        decode_status(buf, 1)
+    }
 }
 
 impl RRxPlWid {
@@ -417,20 +1174,115 @@ impl RRxPlWid {
     pub const fn bytes() -> [u8; 2] {
         [Self::WORD, 0]
     }
+
+    /// Parse the bytes shifted out on MISO during the command.
+    pub const fn parse(bytes: &[u8; 2]) -> RxPlWidResponse {
+        RxPlWidResponse {
+            status: registers::Status::from_bits(bytes[0]),
+            width: bytes[1],
+        }
+    }
+
+    /// Write the command's SPI byte sequence to the start of `buf` and return its length.
+    pub const fn encode_into(buf: &mut [u8]) -> Result<usize, Error> {
+        encode_word_read(Self::WORD, 1, buf)
+    }
+
+    /// Decode the bytes shifted out on MISO during the command from the start of `buf`.
+    pub const fn decode(buf: &[u8]) -> Result<RxPlWidResponse, Error> {
+        if let Err(e) = check_len(buf, 2) {
+            return Err(e);
+        }
+        Ok(Self::parse(&[buf[0], buf[1]]))
+    }
+}
+
+impl<const N: usize> WAckPayload<N> {
+    /// Generate the command's SPI byte sequence in a fixed size buffer.
+    pub const fn spi_bytes(&self) -> SpiBytes {
+        let () = PayloadWidth::<N>::ASSERT;
+        SpiBytes::with_word(Self::WORD | self.pipe.into_bits(), &self.payload)
+    }
+
+    /// Parse the bytes shifted out on MISO during the command.
+    ///
+    /// # Panics
+    /// If `bytes` is empty.
+    pub const fn parse_slice(bytes: &[u8]) -> registers::Status {
+        let () = PayloadWidth::<N>::ASSERT;
+        registers::Status::from_bits(bytes[0])
+    }
+
+    /// Write the command's SPI byte sequence to the start of `buf` and return its length.
+    pub const fn encode_into(&self, buf: &mut [u8]) -> Result<usize, Error> {
+        let () = PayloadWidth::<N>::ASSERT;
+        encode_word_then(Self::WORD | self.pipe.into_bits(), &self.payload, buf)
+    }
+
+    /// Decode the bytes shifted out on MISO during the command from the start of `buf`.
+    pub const fn decode(buf: &[u8]) -> Result<registers::Status, Error> {
+        let () = PayloadWidth::<N>::ASSERT;
+        decode_status(buf, N + 1)
+    }
 }
 
+#[cfg(feature = "nightly")]
 impl<const N: usize> WAckPayload<N> {
     /// Generate the command's SPI byte sequence.
     pub const fn bytes(&self) -> [u8; N + 1] {
-        concat_word_payload(Self::WORD | self.pipe, self.payload)
+        let () = PayloadWidth::<N>::ASSERT;
+        concat_word_payload(Self::WORD | self.pipe.into_bits(), self.payload)
+    }
+
+    /// Parse the bytes shifted out on MISO during the command.
+    pub const fn parse(bytes: &[u8; N + 1]) -> registers::Status {
+        let () = PayloadWidth::<N>::ASSERT;
+        registers::Status::from_bits(bytes[0])
+    }
+}
+
+impl<const N: usize> WTxPayloadNoack<N> {
+    /// Generate the command's SPI byte sequence in a fixed size buffer.
+    pub const fn spi_bytes(&self) -> SpiBytes {
+        let () = PayloadWidth::<N>::ASSERT;
+        SpiBytes::with_word(Self::WORD, &self.0)
+    }
+
+    /// Parse the bytes shifted out on MISO during the command.
+    ///
+    /// # Panics
+    /// If `bytes` is empty.
+    pub const fn parse_slice(bytes: &[u8]) -> registers::Status {
+        let () = PayloadWidth::<N>::ASSERT;
+        registers::Status::from_bits(bytes[0])
+    }
+
+    /// Write the command's SPI byte sequence to the start of `buf` and return its length.
+    pub const fn encode_into(&self, buf: &mut [u8]) -> Result<usize, Error> {
+        let () = PayloadWidth::<N>::ASSERT;
+        encode_word_then(Self::WORD, &self.0, buf)
+    }
+
+    /// Decode the bytes shifted out on MISO during the command from the start of `buf`.
+    pub const fn decode(buf: &[u8]) -> Result<registers::Status, Error> {
+        let () = PayloadWidth::<N>::ASSERT;
+        decode_status(buf, N + 1)
     }
 }
 
+#[cfg(feature = "nightly")]
 impl<const N: usize> WTxPayloadNoack<N> {
     /// Generate the command's SPI byte sequence.
     pub const fn bytes(&self) -> [u8; N + 1] {
+        let () = PayloadWidth::<N>::ASSERT;
         concat_word_payload(Self::WORD, self.0)
     }
+
+    /// Parse the bytes shifted out on MISO during the command.
+    pub const fn parse(bytes: &[u8; N + 1]) -> registers::Status {
+        let () = PayloadWidth::<N>::ASSERT;
+        registers::Status::from_bits(bytes[0])
+    }
 }
 
 impl Nop {
@@ -438,6 +1290,247 @@ impl Nop {
     pub const fn bytes() -> [u8; 1] {
         [Self::WORD]
     }
+
+    /// Parse the bytes shifted out on MISO during the command.
+    pub const fn parse(bytes: &[u8; 1]) -> registers::Status {
+        registers::Status::from_bits(bytes[0])
+    }
+
+    /// Write the command's SPI byte sequence to the start of `buf` and return its length.
+    pub const fn encode_into(buf: &mut [u8]) -> Result<usize, Error> {
+        encode_word_then(Self::WORD, &[], buf)
+    }
+
+    /// Decode the bytes shifted out on MISO during the command from the start of `buf`.
+    pub const fn decode(buf: &[u8]) -> Result<registers::Status, Error> {
+        decode_status(buf, 1)
+    }
+}
+
+impl ClearInterrupts {
+    /// Clear no flag.
+    pub const fn new() -> Self {
+        Self {
+            rx_dr: false,
+            tx_ds: false,
+            max_rt: false,
+        }
+    }
+
+    /// Clear every flag.
+    pub const fn all() -> Self {
+        Self {
+            rx_dr: true,
+            tx_ds: true,
+            max_rt: true,
+        }
+    }
+
+    /// Clear a single flag.
+    pub const fn only(interrupt: fields::Interrupt) -> Self {
+        Self::new().with(interrupt)
+    }
+
+    /// Clear the flags in `interrupts`, e.g. the ones that were handled.
+    pub const fn from_interrupts(interrupts: registers::Interrupts) -> Self {
+        Self {
+            rx_dr: interrupts.contains(fields::Interrupt::RxDr),
+            tx_ds: interrupts.contains(fields::Interrupt::TxDs),
+            max_rt: interrupts.contains(fields::Interrupt::MaxRt),
+        }
+    }
+
+    /// Also clear `interrupt`.
+    pub const fn with(mut self, interrupt: fields::Interrupt) -> Self {
+        match interrupt {
+            fields::Interrupt::RxDr => self.rx_dr = true,
+            fields::Interrupt::TxDs => self.tx_ds = true,
+            fields::Interrupt::MaxRt => self.max_rt = true,
+        }
+        self
+    }
+
+    /// Value written to the [`STATUS`][registers::Status] register.
+    pub const fn status(&self) -> registers::Status {
+        registers::Status::new()
+            .with_rx_dr(self.rx_dr)
+            .with_tx_ds(self.tx_ds)
+            .with_max_rt(self.max_rt)
+    }
+
+    /// Generate the command's SPI byte sequence.
+    pub const fn bytes(&self) -> [u8; 2] {
+        [Self::WORD, self.status().into_bits()]
+    }
+
+    /// Parse the bytes shifted out on MISO during the command.
+    pub const fn parse(bytes: &[u8; 2]) -> registers::Status {
+        registers::Status::from_bits(bytes[0])
+    }
+
+    /// Write the command's SPI byte sequence to the start of `buf` and return its length.
+    pub const fn encode_into(&self, buf: &mut [u8]) -> Result<usize, Error> {
+        encode_word_then(Self::WORD, &[self.status().into_bits()], buf)
+    }
+
+    /// Decode the bytes shifted out on MISO during the command from the start of `buf`.
+    pub const fn decode(buf: &[u8]) -> Result<registers::Status, Error> {
+        decode_status(buf, 2)
+    }
+}
+
+impl<'a> RRxPayloadSlice<'a> {
+    /// Create the command reading into `payload`.
+    ///
+    /// Returns [`Error::InvalidPayloadWidth`] if `payload` is not 1 to 32 bytes.
+    pub const fn new(payload: &'a mut [u8]) -> Result<Self, Error> {
+        match check_payload_width(payload.len()) {
+            Ok(()) => Ok(Self(payload)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Width of the payload to read.
+    pub const fn width(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Slice the payload is read into.
+    pub const fn payload_mut(&mut self) -> &mut [u8] {
+        self.0
+    }
+
+    /// Generate the command's SPI byte sequence in a fixed size buffer.
+    pub const fn spi_bytes(&self) -> SpiBytes {
+        let mut bytes = SpiBytes::new(&[Self::WORD]);
+        bytes.len += self.width();
+        bytes
+    }
+
+    /// Write the command's SPI byte sequence to the start of `buf` and return its length.
+    pub const fn encode_into(&self, buf: &mut [u8]) -> Result<usize, Error> {
+        encode_word_read(Self::WORD, self.width(), buf)
+    }
+
+    /// Decode the bytes shifted out on MISO during the command from the start of `buf`,
+    /// copying the payload into the command's slice.
+    pub const fn decode(self, buf: &[u8]) -> Result<registers::Status, Error> {
+        if let Err(e) = check_len(buf, self.width() + 1) {
+            return Err(e);
+        }
+        let mut i = 0;
+        while i < self.0.len() {
+            self.0[i] = buf[i + 1];
+            i += 1;
+        }
+        Ok(registers::Status::from_bits(buf[0]))
+    }
+}
+
+impl<'a> WTxPayloadSlice<'a> {
+    /// Create the command writing `payload`.
+    ///
+    /// Returns [`Error::InvalidPayloadWidth`] if `payload` is not 1 to 32 bytes.
+    pub const fn new(payload: &'a [u8]) -> Result<Self, Error> {
+        match check_payload_width(payload.len()) {
+            Ok(()) => Ok(Self(payload)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Payload to write.
+    pub const fn payload(&self) -> &'a [u8] {
+        self.0
+    }
+
+    /// Generate the command's SPI byte sequence in a fixed size buffer.
+    pub const fn spi_bytes(&self) -> SpiBytes {
+        SpiBytes::with_word(Self::WORD, self.0)
+    }
+
+    /// Write the command's SPI byte sequence to the start of `buf` and return its length.
+    pub const fn encode_into(&self, buf: &mut [u8]) -> Result<usize, Error> {
+        encode_word_then(Self::WORD, self.0, buf)
+    }
+
+    /// Decode the bytes shifted out on MISO during the command from the start of `buf`.
+    pub const fn decode(&self, buf: &[u8]) -> Result<registers::Status, Error> {
+        decode_status(buf, self.0.len() + 1)
+    }
+}
+
+impl<'a> WAckPayloadSlice<'a> {
+    /// Create the command writing `payload` for a data `pipe`.
+    ///
+    /// Returns [`Error::InvalidPayloadWidth`] if `payload` is not 1 to 32 bytes.
+    pub const fn new(pipe: fields::Pipe, payload: &'a [u8]) -> Result<Self, Error> {
+        match check_payload_width(payload.len()) {
+            Ok(()) => Ok(Self { pipe, payload }),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Data pipe this ACK payload is designated to.
+    pub const fn pipe(&self) -> fields::Pipe {
+        self.pipe
+    }
+
+    /// Payload to send with ACK.
+    pub const fn payload(&self) -> &'a [u8] {
+        self.payload
+    }
+
+    /// Get the command's _command word_.
+    pub const fn word(&self) -> u8 {
+        Self::WORD | self.pipe.into_bits()
+    }
+
+    /// Generate the command's SPI byte sequence in a fixed size buffer.
+    pub const fn spi_bytes(&self) -> SpiBytes {
+        SpiBytes::with_word(self.word(), self.payload)
+    }
+
+    /// Write the command's SPI byte sequence to the start of `buf` and return its length.
+    pub const fn encode_into(&self, buf: &mut [u8]) -> Result<usize, Error> {
+        encode_word_then(self.word(), self.payload, buf)
+    }
+
+    /// Decode the bytes shifted out on MISO during the command from the start of `buf`.
+    pub const fn decode(&self, buf: &[u8]) -> Result<registers::Status, Error> {
+        decode_status(buf, self.payload.len() + 1)
+    }
+}
+
+impl<'a> WTxPayloadNoackSlice<'a> {
+    /// Create the command writing `payload`.
+    ///
+    /// Returns [`Error::InvalidPayloadWidth`] if `payload` is not 1 to 32 bytes.
+    pub const fn new(payload: &'a [u8]) -> Result<Self, Error> {
+        match check_payload_width(payload.len()) {
+            Ok(()) => Ok(Self(payload)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Payload to write.
+    pub const fn payload(&self) -> &'a [u8] {
+        self.0
+    }
+
+    /// Generate the command's SPI byte sequence in a fixed size buffer.
+    pub const fn spi_bytes(&self) -> SpiBytes {
+        SpiBytes::with_word(Self::WORD, self.0)
+    }
+
+    /// Write the command's SPI byte sequence to the start of `buf` and return its length.
+    pub const fn encode_into(&self, buf: &mut [u8]) -> Result<usize, Error> {
+        encode_word_then(Self::WORD, self.0, buf)
+    }
+
+    /// Decode the bytes shifted out on MISO during the command from the start of `buf`.
+    pub const fn decode(&self, buf: &[u8]) -> Result<registers::Status, Error> {
+        decode_status(buf, self.0.len() + 1)
+    }
 }
 
 #[cfg(test)]
@@ -446,6 +1539,7 @@ mod tests {
     use crate::registers;
 
     #[test]
+    #[cfg(feature = "nightly")]
     fn test_read_address_registers() {
         const READ_RX_ADDR_P0_BYTES: [u8; 5] = RRegister::<registers::RxAddrP0<4>>::bytes();
         assert_eq!(READ_RX_ADDR_P0_BYTES, [0x0A, 0, 0, 0, 0]);
@@ -458,6 +1552,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "nightly")]
     fn test_write_address_registers() {
         const RX_ADDR_P0: registers::RxAddrP0<5> =
             registers::RxAddrP0::<5>::new().with_rx_addr_p0(0x8106310AC0);
@@ -480,4 +1575,286 @@ mod tests {
         const TX_ADDR_BYTES: [u8; 4] = WRegister(TX_ADDR).bytes();
         assert_eq!(TX_ADDR_BYTES, [0b0010_0000 | 0x10, 0x07, 0xED, 0xC8]);
     }
+
+    #[test]
+    #[cfg(feature = "nightly")]
+    fn test_parse_address_registers() {
+        const RX_ADDR_P0: RegisterResponse<registers::RxAddrP0<4>> =
+            RRegister::<registers::RxAddrP0<4>>::parse(&[0b0000_1110, 0x58, 0x6C, 0x3A, 0xE7]);
+        assert_eq!(RX_ADDR_P0.status.into_bits(), 0b0000_1110);
+        assert_eq!(RX_ADDR_P0.register.into_bits(), 0xE73A6C58);
+
+        const RX_ADDR_P1: RegisterResponse<registers::RxAddrP1<3>> =
+            RRegister::<registers::RxAddrP1<3>>::parse(&[0b0100_0010, 0xBF, 0x59, 0x44]);
+        assert!(RX_ADDR_P1.status.rx_dr());
+        assert_eq!(RX_ADDR_P1.register.into_bits(), 0x4459BF);
+
+        // Round-trip write and read of TX_ADDR
+        let tx_addr = registers::TxAddr::<5>::new().with_tx_addr(0xA2891FFF6A);
+        let mut miso = WRegister(tx_addr).bytes();
+        miso[0] = 0b0000_1110;
+        let response = RRegister::<registers::TxAddr<5>>::parse(&miso);
+        assert_eq!(response.register.tx_addr(), 0xA2891FFF6A);
+    }
+
+    #[test]
+    #[cfg(feature = "nightly")]
+    fn test_parse_status() {
+        const STATUS_BYTE: u8 = 0b0001_1110;
+        assert!(WRegister::<registers::Config>::parse(&[STATUS_BYTE, 0]).max_rt());
+        assert!(WTxPayload::<3>::parse(&[STATUS_BYTE, 0, 0, 0]).max_rt());
+        assert!(WTxPayloadNoack::<1>::parse(&[STATUS_BYTE, 0]).max_rt());
+        assert!(WAckPayload::<2>::parse(&[STATUS_BYTE, 0, 0]).max_rt());
+        assert!(FlushTx::parse(&[STATUS_BYTE]).max_rt());
+        assert!(FlushRx::parse(&[STATUS_BYTE]).max_rt());
+        assert!(ReuseTxPl::parse(&[STATUS_BYTE]).max_rt());
+        assert!(Nop::parse(&[STATUS_BYTE]).max_rt());
+    }
+
+    #[test]
+    fn test_clear_interrupts() {
+        assert_eq!(
+            ClearInterrupts::WORD,
+            0b0010_0000 | registers::Status::ADDRESS
+        );
+        assert_eq!(ClearInterrupts::new().bytes(), [0b0010_0111, 0]);
+        assert_eq!(ClearInterrupts::all().bytes(), [0b0010_0111, 0b0111_0000]);
+        let command = ClearInterrupts::only(fields::Interrupt::RxDr).with(fields::Interrupt::MaxRt);
+        assert_eq!(command.bytes(), [0b0010_0111, 0b0101_0000]);
+
+        // Read-only bits of a read status are not written back
+        let status = registers::Status::from_bits(0b0010_1111);
+        let command = ClearInterrupts::from_interrupts(status.interrupts());
+        assert_eq!(
+            command,
+            ClearInterrupts {
+                rx_dr: false,
+                tx_ds: true,
+                max_rt: false
+            }
+        );
+        assert_eq!(command.status().into_bits(), 0b0010_0000);
+
+        let mut buf = [0; 2];
+        assert_eq!(command.encode_into(&mut buf), Ok(2));
+        assert_eq!(buf, [0b0010_0111, 0b0010_0000]);
+        assert!(ClearInterrupts::decode(&[0b0010_1110, 0]).unwrap().tx_ds());
+        assert!(matches!(
+            ClearInterrupts::decode(&[0]),
+            Err(Error::BufferTooShort {
+                required: 2,
+                len: 1
+            })
+        ));
+    }
+
+    #[test]
+    fn test_spi_bytes() {
+        const READ_TX_ADDR: SpiBytes = RRegister::<registers::TxAddr<3>>::spi_bytes();
+        assert_eq!(READ_TX_ADDR.as_slice(), &[0x10, 0, 0, 0]);
+
+        const RX_ADDR_P0: registers::RxAddrP0<5> =
+            registers::RxAddrP0::<5>::new().with_rx_addr_p0(0x8106310AC0);
+        const WRITE_RX_ADDR_P0: SpiBytes = WRegister(RX_ADDR_P0).spi_bytes();
+        assert_eq!(
+            WRITE_RX_ADDR_P0.as_slice(),
+            &[0b0010_0000 | 0x0A, 0xC0, 0x0A, 0x31, 0x06, 0x81]
+        );
+
+        let read_payload = RRxPayload::<32>::spi_bytes();
+        assert_eq!(read_payload.len(), 33);
+        assert_eq!(read_payload.as_slice()[0], 0b0110_0001);
+        assert!(read_payload.as_slice()[1..].iter().all(|&b| b == 0));
+
+        let write_payload = WTxPayload([1, 2, 3]).spi_bytes();
+        assert_eq!(write_payload.as_slice(), &[0b1010_0000, 1, 2, 3]);
+        let write_payload = WTxPayloadNoack([1, 2]).spi_bytes();
+        assert_eq!(write_payload.as_slice(), &[0b1011_0000, 1, 2]);
+        let write_payload = WAckPayload {
+            pipe: fields::Pipe::P3,
+            payload: [7],
+        }
+        .spi_bytes();
+        assert_eq!(write_payload.as_slice(), &[0b1010_1011, 7]);
+    }
+
+    #[test]
+    fn test_parse_slice() {
+        // Round-trip write and read of RX_ADDR_P1 in a single buffer
+        let rx_addr_p1 = registers::RxAddrP1::<4>::new().with_rx_addr_p1(0x605F4459BF);
+        let mut buf = WRegister(rx_addr_p1).spi_bytes();
+        buf.as_mut_slice()[0] = 0b0100_0010;
+        let response = RRegister::<registers::RxAddrP1<4>>::parse_slice(buf.as_slice());
+        assert!(response.status.rx_dr());
+        assert_eq!(response.register.rx_addr_p1(), 0x5F4459BF);
+
+        let response = RRxPayload::<3>::parse_slice(&[0b0100_0000, 1, 2, 3]);
+        assert!(response.status.rx_dr());
+        assert_eq!(response.payload, [1, 2, 3]);
+
+        const STATUS_BYTE: u8 = 0b0001_1110;
+        assert!(WRegister::<registers::TxAddr<5>>::parse_slice(&[STATUS_BYTE]).max_rt());
+        assert!(WTxPayload::<3>::parse_slice(&[STATUS_BYTE, 0, 0, 0]).max_rt());
+        assert!(WTxPayloadNoack::<1>::parse_slice(&[STATUS_BYTE, 0]).max_rt());
+        assert!(WAckPayload::<2>::parse_slice(&[STATUS_BYTE, 0, 0]).max_rt());
+    }
+
+    #[test]
+    fn test_encode_into() {
+        let mut buf = [0xAA; MAX_SPI_BYTES];
+        assert_eq!(RRegister::<registers::RfCh>::encode_into(&mut buf), Ok(2));
+        assert_eq!(buf[..2], [0x05, 0]);
+
+        let rf_ch = registers::RfCh::new().with_rf_ch(85);
+        assert_eq!(WRegister(rf_ch).encode_into(&mut buf), Ok(2));
+        assert_eq!(buf[..2], [0b0010_0000 | 0x05, 85]);
+
+        let tx_addr = registers::TxAddr::<3>::new().with_tx_addr(0xFF32C8ED07);
+        assert_eq!(WRegister(tx_addr).encode_into(&mut buf), Ok(4));
+        assert_eq!(buf[..4], [0b0010_0000 | 0x10, 0x07, 0xED, 0xC8]);
+        assert_eq!(
+            RRegister::<registers::TxAddr<3>>::encode_into(&mut buf),
+            Ok(4)
+        );
+        assert_eq!(buf[..4], [0x10, 0, 0, 0]);
+
+        assert_eq!(
+            WAckPayload {
+                pipe: fields::Pipe::P2,
+                payload: [9; 32]
+            }
+            .encode_into(&mut buf),
+            Ok(33)
+        );
+        assert_eq!(buf[0], 0b1010_1010);
+        assert_eq!(buf[1..], [9; 32]);
+        assert_eq!(RRxPayload::<32>::encode_into(&mut buf), Ok(33));
+        assert_eq!(buf[0], 0b0110_0001);
+        assert_eq!(buf[1..], [0; 32]);
+
+        assert_eq!(WTxPayload([1, 2]).encode_into(&mut buf), Ok(3));
+        assert_eq!(WTxPayloadNoack([1, 2]).encode_into(&mut buf), Ok(3));
+        assert_eq!(buf[..3], [0b1011_0000, 1, 2]);
+        assert_eq!(RRxPlWid::encode_into(&mut buf), Ok(2));
+        assert_eq!(buf[..2], [0b0110_0000, 0]);
+        assert_eq!(FlushTx::encode_into(&mut buf), Ok(1));
+        assert_eq!(buf[0], 0b1110_0001);
+        assert_eq!(Nop::encode_into(&mut buf[..1]), Ok(1));
+
+        // The buffer is left untouched if it is too short
+        let mut short = [0xAA; 4];
+        assert_eq!(
+            WTxPayload([1, 2, 3, 4]).encode_into(&mut short),
+            Err(Error::BufferTooShort {
+                required: 5,
+                len: 4
+            })
+        );
+        assert_eq!(short, [0xAA; 4]);
+        assert!(ReuseTxPl::encode_into(&mut []).is_err());
+    }
+
+    #[test]
+    fn test_decode() {
+        // Full-duplex transfer on a single buffer
+        let mut buf = [0; MAX_SPI_BYTES];
+        let len = RRegister::<registers::RxAddrP0<5>>::encode_into(&mut buf).unwrap();
+        buf[..len].copy_from_slice(&[0b0000_1110, 0xC0, 0x0A, 0x31, 0x06, 0x81]);
+        let response = RRegister::<registers::RxAddrP0<5>>::decode(&buf).unwrap();
+        assert_eq!(response.register.rx_addr_p0(), 0x8106310AC0);
+
+        let len = RRxPayload::<3>::encode_into(&mut buf).unwrap();
+        buf[..len].copy_from_slice(&[0b0100_0010, 4, 5, 6]);
+        let response = RRxPayload::<3>::decode_ref(&buf).unwrap();
+        assert_eq!(response.status.rx_p_no(), crate::fields::RxPipeNo::Pipe1);
+        assert_eq!(response.payload, &[4, 5, 6]);
+        assert_eq!(RRxPayload::<3>::decode(&buf).unwrap().payload, [4, 5, 6]);
+
+        let response = RRegister::<registers::FifoStatus>::decode(&[0b0000_1110, 0b0001_0001]);
+        assert!(response.unwrap().register.tx_empty());
+        assert_eq!(RRxPlWid::decode(&[0b0100_0000, 17]).unwrap().width, 17);
+
+        const STATUS_BYTE: u8 = 0b0001_1110;
+        assert!(
+            WRegister::<registers::Config>::decode(&[STATUS_BYTE, 0])
+                .unwrap()
+                .max_rt()
+        );
+        assert!(
+            WRegister::<registers::TxAddr<3>>::decode(&[STATUS_BYTE, 0, 0, 0])
+                .unwrap()
+                .max_rt()
+        );
+        assert!(
+            WTxPayload::<2>::decode(&[STATUS_BYTE, 0, 0])
+                .unwrap()
+                .max_rt()
+        );
+        assert!(
+            WAckPayload::<1>::decode(&[STATUS_BYTE, 0])
+                .unwrap()
+                .max_rt()
+        );
+        assert!(
+            WTxPayloadNoack::<1>::decode(&[STATUS_BYTE, 0])
+                .unwrap()
+                .max_rt()
+        );
+        assert!(FlushRx::decode(&[STATUS_BYTE]).unwrap().max_rt());
+        assert!(Nop::decode(&[STATUS_BYTE]).unwrap().max_rt());
+
+        assert_eq!(
+            RRxPayload::<4>::decode_ref(&[0; 4]).unwrap_err(),
+            Error::BufferTooShort {
+                required: 5,
+                len: 4
+            }
+        );
+        assert!(RRegister::<registers::Config>::decode(&[0]).is_err());
+        assert!(FlushTx::decode(&[]).is_err());
+    }
+
+    #[test]
+    fn test_payload_slices() {
+        let mut buf = [0; MAX_SPI_BYTES];
+        let payload = [0x5A; 32];
+
+        let command = WTxPayloadSlice::new(&payload).unwrap();
+        assert_eq!(
+            command.spi_bytes().as_slice(),
+            WTxPayload(payload).spi_bytes().as_slice()
+        );
+        assert_eq!(command.encode_into(&mut buf), Ok(33));
+        assert_eq!(buf[0], 0b1010_0000);
+        assert!(command.decode(&[0b0001_1110]).is_err());
+
+        let command = WTxPayloadNoackSlice::new(&payload[..1]).unwrap();
+        assert_eq!(command.encode_into(&mut buf), Ok(2));
+        assert_eq!(buf[..2], [0b1011_0000, 0x5A]);
+        assert!(command.decode(&buf).is_ok());
+
+        let command = WAckPayloadSlice::new(fields::Pipe::P1, &payload[..2]).unwrap();
+        assert_eq!(command.spi_bytes().as_slice(), &[0b1010_1001, 0x5A, 0x5A]);
+        assert_eq!(command.pipe(), fields::Pipe::P1);
+
+        let mut rx_payload = [0; 4];
+        let command = RRxPayloadSlice::new(&mut rx_payload).unwrap();
+        assert_eq!(command.spi_bytes().as_slice(), &[0b0110_0001, 0, 0, 0, 0]);
+        assert_eq!(command.encode_into(&mut buf), Ok(5));
+        buf[..5].copy_from_slice(&[0b0100_0000, 1, 2, 3, 4]);
+        assert!(command.decode(&buf).unwrap().rx_dr());
+        assert_eq!(rx_payload, [1, 2, 3, 4]);
+
+        // Payload must be 1 to 32 bytes
+        assert_eq!(
+            WTxPayloadSlice::new(&[]).unwrap_err(),
+            Error::InvalidPayloadWidth { width: 0 }
+        );
+        assert_eq!(
+            WTxPayloadNoackSlice::new(&[0; 33]).unwrap_err(),
+            Error::InvalidPayloadWidth { width: 33 }
+        );
+        assert!(WAckPayloadSlice::new(fields::Pipe::P0, &[0; 40]).is_err());
+        assert!(RRxPayloadSlice::new(&mut [0; 33]).is_err());
+    }
 }
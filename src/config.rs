@@ -0,0 +1,597 @@
+//! Whole-chip radio configuration.
+//!
+//! [`RadioConfig`] aggregates every configuration register and generates the ordered
+//! sequence of commands that applies it, in `const` context.
+//!
+//! ## Example
+//! ```rust
+//! use nrf24l01_commands::{commands::SpiBytes, config::{self, RadioConfig}, fields, registers};
+//! use nrf24l01_commands::registers::AddressRegister;
+//!
+//! const RADIO: RadioConfig<4> = RadioConfig::new()
+//!     .with_config(registers::Config::new().with_pwr_up(true).with_prim_rx(true))
+//!     .with_rf_ch(registers::RfCh::new().with_rf_ch(76))
+//!     .with_rf_setup(registers::RfSetup::new().with_rf_dr_high(fields::RfDrHigh::Mbps1))
+//!     .with_rx_pw_p0(registers::RxPwP0::new().with_rx_pw_p0(8));
+//! const INIT: config::InitSequence = RADIO.init_sequence();
+//!
+//! // Address width is written first
+//! assert_eq!(INIT[0].as_slice(), &[0b0010_0011, 0b10]);
+//! // CONFIG is written last to power up
+//! assert_eq!(INIT[config::INIT_SEQUENCE_LEN - 1].as_slice(), &[0b0010_0000, 0b0000_1011]);
+//! // RX_ADDR_P0 with 4 bytes
+//! assert!(INIT.contains(&SpiBytes::new(&[0b0010_1010, 0xE7, 0xE7, 0xE7, 0xE7])));
+//!
+//! // Address registers are set at runtime
+//! let radio = RADIO.with_tx_addr(registers::TxAddr::<4>::new().with_tx_addr(0xA1B2C3D4));
+//! let init = radio.init_sequence();
+//! assert!(init.contains(&SpiBytes::new(&[0b0011_0000, 0xD4, 0xC3, 0xB2, 0xA1])));
+//! ```
+use crate::airtime;
+use crate::commands::{self, SpiBytes};
+use crate::fields;
+use crate::registers;
+use core::fmt;
+
+/// Number of commands in an [`InitSequence`].
+pub const INIT_SEQUENCE_LEN: usize = 25;
+
+/// SPI byte sequences generated by [`RadioConfig::init_sequence`].
+pub type InitSequence = [SpiBytes; INIT_SEQUENCE_LEN];
+
+/// Maximum number of errors found by [`RadioConfig::validate`].
+pub const MAX_VALIDATION_ERRORS: usize = 2 + 3 * fields::PIPES + 1;
+
+/// Address width `N` of a configuration, checked at compile time.
+struct AddressWidth<const N: usize>;
+
+impl<const N: usize> AddressWidth<N> {
+    /// Fails to compile, when used, if `N` is not 3 to 5.
+    const ASSERT: () = assert!(N >= 3 && N <= 5, "address width must be 3 to 5 bytes");
+}
+
+/// Invalid combination of register values.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ConfigError {
+    /// `FEATURE.en_ack_pay` is set without `FEATURE.en_dpl`.
+    AckPayloadWithoutDynamicPayload,
+    /// `DYNPD.dpl_p{pipe}` is set without `FEATURE.en_dpl`.
+    DynamicPayloadNotEnabled {
+        /// Data pipe with dynamic payload length.
+        pipe: fields::Pipe,
+    },
+    /// `DYNPD.dpl_p{pipe}` is set without `EN_AA.enaa_p{pipe}`.
+    DynamicPayloadWithoutAutoAck {
+        /// Data pipe with dynamic payload length.
+        pipe: fields::Pipe,
+    },
+    /// `SETUP_AW.aw` is [`AddressWidth::Illegal`](fields::AddressWidth::Illegal).
+    IllegalAddressWidth,
+    /// `SETUP_AW.aw` does not match the width of the address registers.
+    AddressWidthMismatch {
+        /// Value of `SETUP_AW.aw`.
+        aw: fields::AddressWidth,
+        /// Width of the address registers in bytes.
+        width: u8,
+    },
+    /// `RX_PW_P{pipe}.rx_pw_p{pipe}` is above 32.
+    PayloadWidthTooLarge {
+        /// Data pipe.
+        pipe: fields::Pipe,
+        /// Value of `RX_PW_P{pipe}.rx_pw_p{pipe}`.
+        width: u8,
+    },
+    /// `SETUP_RETR.ard` is too short for the data rate in `RF_SETUP` and the ACK payload size.
+    RetransmitDelayTooShort {
+        /// Value of `SETUP_RETR.ard`.
+        ard: fields::AutoRetransmitDelay,
+        /// Minimum retransmit delay.
+        min: fields::AutoRetransmitDelay,
+    },
+}
+
+impl ConfigError {
+    /// Name of the offending register.
+    pub const fn register(&self) -> &'static str {
+        match self {
+            Self::AckPayloadWithoutDynamicPayload => "FEATURE",
+            Self::DynamicPayloadNotEnabled { .. } | Self::DynamicPayloadWithoutAutoAck { .. } => {
+                "DYNPD"
+            }
+            Self::IllegalAddressWidth | Self::AddressWidthMismatch { .. } => "SETUP_AW",
+            Self::PayloadWidthTooLarge { pipe, .. } => [
+                "RX_PW_P0", "RX_PW_P1", "RX_PW_P2", "RX_PW_P3", "RX_PW_P4", "RX_PW_P5",
+            ][pipe.into_bits() as usize],
+            Self::RetransmitDelayTooShort { .. } => "SETUP_RETR",
+        }
+    }
+
+    /// Name of the offending field.
+    pub const fn field(&self) -> &'static str {
+        match self {
+            Self::AckPayloadWithoutDynamicPayload => "en_ack_pay",
+            Self::DynamicPayloadNotEnabled { pipe }
+            | Self::DynamicPayloadWithoutAutoAck { pipe } => {
+                ["dpl_p0", "dpl_p1", "dpl_p2", "dpl_p3", "dpl_p4", "dpl_p5"]
+                    [pipe.into_bits() as usize]
+            }
+            Self::IllegalAddressWidth | Self::AddressWidthMismatch { .. } => "aw",
+            Self::PayloadWidthTooLarge { pipe, .. } => [
+                "rx_pw_p0", "rx_pw_p1", "rx_pw_p2", "rx_pw_p3", "rx_pw_p4", "rx_pw_p5",
+            ][pipe.into_bits() as usize],
+            Self::RetransmitDelayTooShort { .. } => "ard",
+        }
+    }
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}: ", self.register(), self.field())?;
+        match self {
+            Self::AckPayloadWithoutDynamicPayload => {
+                write!(f, "ACK payload requires FEATURE.en_dpl")
+            }
+            Self::DynamicPayloadNotEnabled { .. } => {
+                write!(f, "dynamic payload length requires FEATURE.en_dpl")
+            }
+            Self::DynamicPayloadWithoutAutoAck { pipe } => {
+                write!(
+                    f,
+                    "dynamic payload length requires EN_AA.enaa_p{}",
+                    pipe.into_bits()
+                )
+            }
+            Self::IllegalAddressWidth => write!(f, "illegal address width"),
+            Self::AddressWidthMismatch { aw, width } => {
+                write!(f, "{aw:?} does not match {width} byte address registers")
+            }
+            Self::PayloadWidthTooLarge { width, .. } => {
+                write!(f, "payload width {width} is above 32 bytes")
+            }
+            Self::RetransmitDelayTooShort { ard, min } => {
+                write!(f, "{ard:?} is shorter than the minimum {min:?}")
+            }
+        }
+    }
+}
+
+/// Errors found by [`RadioConfig::validate`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ValidationErrors {
+    errors: [Option<ConfigError>; MAX_VALIDATION_ERRORS],
+    len: usize,
+}
+
+impl ValidationErrors {
+    const fn new() -> Self {
+        Self {
+            errors: [None; MAX_VALIDATION_ERRORS],
+            len: 0,
+        }
+    }
+
+    const fn push(&mut self, error: ConfigError) {
+        self.errors[self.len] = Some(error);
+        self.len += 1;
+    }
+
+    /// Number of errors.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether there are no errors.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Error at `index`.
+    pub const fn get(&self, index: usize) -> Option<ConfigError> {
+        if index < self.len {
+            self.errors[index]
+        } else {
+            None
+        }
+    }
+
+    /// Whether `error` was found.
+    pub fn contains(&self, error: &ConfigError) -> bool {
+        self.iter().any(|e| e == *error)
+    }
+
+    /// Iterate over the errors.
+    pub fn iter(&self) -> impl Iterator<Item = ConfigError> + '_ {
+        self.errors[..self.len].iter().flatten().copied()
+    }
+}
+
+macro_rules! register_fields {
+    ($($field:ident: $reg:ty, $with:ident, $doc:literal;)*) => {
+        /// Configuration of every nRF24L01+ configuration register.
+        ///
+        /// #### Const Parameter `N`
+        /// Address width of `RX_ADDR_P0`, `RX_ADDR_P1` and `TX_ADDR` in bytes, 3-5.
+        #[derive(Copy, Clone)]
+        pub struct RadioConfig<const N: usize> {
+            $($field: $reg,)*
+        }
+
+        impl<const N: usize> RadioConfig<N> {
+            $(
+                #[doc = concat!("The ", $doc, " register.")]
+                pub const fn $field(&self) -> $reg {
+                    self.$field
+                }
+
+                #[doc = concat!("Set the ", $doc, " register.")]
+                pub const fn $with(mut self, $field: $reg) -> Self {
+                    self.$field = $field;
+                    self
+                }
+            )*
+        }
+    };
+}
+
+register_fields! {
+    config: registers::Config, with_config, "CONFIG";
+    en_aa: registers::EnAa, with_en_aa, "EN_AA";
+    en_rxaddr: registers::EnRxaddr, with_en_rxaddr, "EN_RXADDR";
+    setup_aw: registers::SetupAw, with_setup_aw, "SETUP_AW";
+    setup_retr: registers::SetupRetr, with_setup_retr, "SETUP_RETR";
+    rf_ch: registers::RfCh, with_rf_ch, "RF_CH";
+    rf_setup: registers::RfSetup, with_rf_setup, "RF_SETUP";
+    rx_addr_p0: registers::RxAddrP0<N>, with_rx_addr_p0, "RX_ADDR_P0";
+    rx_addr_p1: registers::RxAddrP1<N>, with_rx_addr_p1, "RX_ADDR_P1";
+    rx_addr_p2: registers::RxAddrP2, with_rx_addr_p2, "RX_ADDR_P2";
+    rx_addr_p3: registers::RxAddrP3, with_rx_addr_p3, "RX_ADDR_P3";
+    rx_addr_p4: registers::RxAddrP4, with_rx_addr_p4, "RX_ADDR_P4";
+    rx_addr_p5: registers::RxAddrP5, with_rx_addr_p5, "RX_ADDR_P5";
+    tx_addr: registers::TxAddr<N>, with_tx_addr, "TX_ADDR";
+    rx_pw_p0: registers::RxPwP0, with_rx_pw_p0, "RX_PW_P0";
+    rx_pw_p1: registers::RxPwP1, with_rx_pw_p1, "RX_PW_P1";
+    rx_pw_p2: registers::RxPwP2, with_rx_pw_p2, "RX_PW_P2";
+    rx_pw_p3: registers::RxPwP3, with_rx_pw_p3, "RX_PW_P3";
+    rx_pw_p4: registers::RxPwP4, with_rx_pw_p4, "RX_PW_P4";
+    rx_pw_p5: registers::RxPwP5, with_rx_pw_p5, "RX_PW_P5";
+    dynpd: registers::Dynpd, with_dynpd, "DYNPD";
+    feature: registers::Feature, with_feature, "FEATURE";
+}
+
+impl<const N: usize> RadioConfig<N> {
+    /// Create a configuration with every register at its reset value,
+    /// except `SETUP_AW` which is set to `N` bytes.
+    ///
+    /// Fails to compile if `N` is not 3 to 5:
+    /// ```rust,compile_fail,E0080
+    /// use nrf24l01_commands::config::RadioConfig;
+    ///
+    /// let radio = RadioConfig::<5>::new();
+    /// let radio = RadioConfig::<6>::new();
+    /// ```
+    pub const fn new() -> Self {
+        let () = AddressWidth::<N>::ASSERT;
+        let aw = match N {
+            3 => fields::AddressWidth::ThreeByte,
+            4 => fields::AddressWidth::FourByte,
+            _ => fields::AddressWidth::FiveByte,
+        };
+        Self {
+            config: registers::Config::new(),
+            en_aa: registers::EnAa::new(),
+            en_rxaddr: registers::EnRxaddr::new(),
+            setup_aw: registers::SetupAw::new().with_aw(aw),
+            setup_retr: registers::SetupRetr::new(),
+            rf_ch: registers::RfCh::new(),
+            rf_setup: registers::RfSetup::new(),
+            rx_addr_p0: registers::RxAddrP0::<N>::new(),
+            rx_addr_p1: registers::RxAddrP1::<N>::new(),
+            rx_addr_p2: registers::RxAddrP2::new(),
+            rx_addr_p3: registers::RxAddrP3::new(),
+            rx_addr_p4: registers::RxAddrP4::new(),
+            rx_addr_p5: registers::RxAddrP5::new(),
+            tx_addr: registers::TxAddr::<N>::new(),
+            rx_pw_p0: registers::RxPwP0::new(),
+            rx_pw_p1: registers::RxPwP1::new(),
+            rx_pw_p2: registers::RxPwP2::new(),
+            rx_pw_p3: registers::RxPwP3::new(),
+            rx_pw_p4: registers::RxPwP4::new(),
+            rx_pw_p5: registers::RxPwP5::new(),
+            dynpd: registers::Dynpd::new(),
+            feature: registers::Feature::new(),
+        }
+    }
+
+    /// Generate the SPI byte sequences that apply the configuration, in order:
+    /// 1. `SETUP_AW`, then the remaining registers with `FEATURE` before `DYNPD`
+    /// 2. `FLUSH_TX` and `FLUSH_RX`
+    /// 3. Clear `rx_dr`, `tx_ds` and `max_rt` in `STATUS`
+    /// 4. `CONFIG`, last so the chip only powers up once configured
+    pub const fn init_sequence(&self) -> InitSequence {
+        let clear_status = registers::Status::new()
+            .with_rx_dr(true)
+            .with_tx_ds(true)
+            .with_max_rt(true);
+        [
+            SpiBytes::new(&commands::WRegister(self.setup_aw).bytes()),
+            SpiBytes::new(&commands::WRegister(self.en_aa).bytes()),
+            SpiBytes::new(&commands::WRegister(self.en_rxaddr).bytes()),
+            SpiBytes::new(&commands::WRegister(self.setup_retr).bytes()),
+            SpiBytes::new(&commands::WRegister(self.rf_ch).bytes()),
+            SpiBytes::new(&commands::WRegister(self.rf_setup).bytes()),
+            SpiBytes::with_word(
+                commands::WRegister::<registers::RxAddrP0<N>>::word(),
+                &self.rx_addr_p0.into_bytes(),
+            ),
+            SpiBytes::with_word(
+                commands::WRegister::<registers::RxAddrP1<N>>::word(),
+                &self.rx_addr_p1.into_bytes(),
+            ),
+            SpiBytes::new(&commands::WRegister(self.rx_addr_p2).bytes()),
+            SpiBytes::new(&commands::WRegister(self.rx_addr_p3).bytes()),
+            SpiBytes::new(&commands::WRegister(self.rx_addr_p4).bytes()),
+            SpiBytes::new(&commands::WRegister(self.rx_addr_p5).bytes()),
+            SpiBytes::with_word(
+                commands::WRegister::<registers::TxAddr<N>>::word(),
+                &self.tx_addr.into_bytes(),
+            ),
+            SpiBytes::new(&commands::WRegister(self.rx_pw_p0).bytes()),
+            SpiBytes::new(&commands::WRegister(self.rx_pw_p1).bytes()),
+            SpiBytes::new(&commands::WRegister(self.rx_pw_p2).bytes()),
+            SpiBytes::new(&commands::WRegister(self.rx_pw_p3).bytes()),
+            SpiBytes::new(&commands::WRegister(self.rx_pw_p4).bytes()),
+            SpiBytes::new(&commands::WRegister(self.rx_pw_p5).bytes()),
+            SpiBytes::new(&commands::WRegister(self.feature).bytes()),
+            SpiBytes::new(&commands::WRegister(self.dynpd).bytes()),
+            SpiBytes::new(&commands::FlushTx::bytes()),
+            SpiBytes::new(&commands::FlushRx::bytes()),
+            SpiBytes::new(&commands::WRegister(clear_status).bytes()),
+            SpiBytes::new(&commands::WRegister(self.config).bytes()),
+        ]
+    }
+}
+
+impl<const N: usize> RadioConfig<N> {
+    /// Check the configuration for register combinations that are invalid according to the
+    /// datasheet.
+    ///
+    /// `max_ack_payload` is the largest ACK payload in bytes that will be written with
+    /// [`W_ACK_PAYLOAD`](commands::WAckPayload). It is only used if `FEATURE.en_ack_pay` is set.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use nrf24l01_commands::{config::{ConfigError, RadioConfig}, fields, registers};
+    ///
+    /// let radio = RadioConfig::<5>::new()
+    ///     .with_feature(registers::Feature::new().with_en_ack_pay(true))
+    ///     .with_rf_setup(registers::RfSetup::new().with_data_rate(fields::DataRate::Kbps250));
+    /// let errors = radio.validate(16).unwrap_err();
+    ///
+    /// assert_eq!(errors.len(), 2);
+    /// let error = errors.get(0).unwrap();
+    /// assert_eq!(error, ConfigError::AckPayloadWithoutDynamicPayload);
+    /// assert_eq!((error.register(), error.field()), ("FEATURE", "en_ack_pay"));
+    /// assert!(errors.contains(&ConfigError::RetransmitDelayTooShort {
+    ///     ard: fields::AutoRetransmitDelay::US250,
+    ///     min: fields::AutoRetransmitDelay::US1000,
+    /// }));
+    /// ```
+    pub const fn validate(&self, max_ack_payload: u8) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+
+        let en_dpl = self.feature.en_dpl();
+        if self.feature.en_ack_pay() && !en_dpl {
+            errors.push(ConfigError::AckPayloadWithoutDynamicPayload);
+        }
+        let dynpd = self.dynpd.into_bits();
+        let en_aa = self.en_aa.into_bits();
+        let mut i = 0;
+        while i < fields::PIPES {
+            let pipe = fields::Pipe::ALL[i];
+            if dynpd >> i & 1 == 1 {
+                if !en_dpl {
+                    errors.push(ConfigError::DynamicPayloadNotEnabled { pipe });
+                }
+                if en_aa >> i & 1 == 0 {
+                    errors.push(ConfigError::DynamicPayloadWithoutAutoAck { pipe });
+                }
+            }
+            i += 1;
+        }
+
+        let aw = self.setup_aw.aw();
+        let width = match aw {
+            fields::AddressWidth::Illegal => 0,
+            fields::AddressWidth::ThreeByte => 3,
+            fields::AddressWidth::FourByte => 4,
+            fields::AddressWidth::FiveByte => 5,
+        };
+        if width == 0 {
+            errors.push(ConfigError::IllegalAddressWidth);
+        } else if width != N {
+            errors.push(ConfigError::AddressWidthMismatch { aw, width: N as u8 });
+        }
+
+        let rx_pw = [
+            self.rx_pw_p0.rx_pw_p0(),
+            self.rx_pw_p1.rx_pw_p1(),
+            self.rx_pw_p2.rx_pw_p2(),
+            self.rx_pw_p3.rx_pw_p3(),
+            self.rx_pw_p4.rx_pw_p4(),
+            self.rx_pw_p5.rx_pw_p5(),
+        ];
+        let mut i = 0;
+        while i < fields::PIPES {
+            let width = rx_pw[i];
+            if width as usize > commands::MAX_PAYLOAD_WIDTH {
+                let pipe = fields::Pipe::ALL[i];
+                errors.push(ConfigError::PayloadWidthTooLarge { pipe, width });
+            }
+            i += 1;
+        }
+
+        let ack_payload = if self.feature.en_ack_pay() {
+            max_ack_payload
+        } else {
+            0
+        };
+        let min = airtime::min_ard(self.rf_setup, ack_payload);
+        let ard = self.setup_retr.ard();
+        if ard.into_bits() < min.into_bits() {
+            errors.push(ConfigError::RetransmitDelayTooShort { ard, min });
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+impl<const N: usize> Default for RadioConfig<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_init_sequence() {
+        const INIT: InitSequence = RadioConfig::<5>::new().init_sequence();
+        let expected: [&[u8]; INIT_SEQUENCE_LEN] = [
+            &[0x23, 0b11],
+            &[0x21, 0b0011_1111],
+            &[0x22, 0b0000_0011],
+            &[0x24, 0b0000_0011],
+            &[0x25, 2],
+            &[0x26, 0b0000_1110],
+            &[0x2A, 0xE7, 0xE7, 0xE7, 0xE7, 0xE7],
+            &[0x2B, 0xC2, 0xC2, 0xC2, 0xC2, 0xC2],
+            &[0x2C, 0xC3],
+            &[0x2D, 0xC4],
+            &[0x2E, 0xC5],
+            &[0x2F, 0xC6],
+            &[0x30, 0xE7, 0xE7, 0xE7, 0xE7, 0xE7],
+            &[0x31, 0],
+            &[0x32, 0],
+            &[0x33, 0],
+            &[0x34, 0],
+            &[0x35, 0],
+            &[0x36, 0],
+            &[0x3D, 0],
+            &[0x3C, 0],
+            &[0b1110_0001],
+            &[0b1110_0010],
+            &[0x27, 0b0111_0000],
+            &[0x20, 0b0000_1000],
+        ];
+        for (bytes, expected) in INIT.iter().zip(expected) {
+            assert_eq!(bytes.as_slice(), expected);
+        }
+    }
+
+    #[test]
+    fn test_validate_default() {
+        assert_eq!(RadioConfig::<5>::new().validate(32), Ok(()));
+        assert_eq!(RadioConfig::<3>::new().validate(32), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_registers() {
+        let radio = RadioConfig::<4>::new()
+            .with_setup_aw(registers::SetupAw::new().with_aw(fields::AddressWidth::Illegal))
+            .with_en_aa(registers::EnAa::from_bits(0b0000_0001))
+            .with_dynpd(registers::Dynpd::new().with_dpl_p0(true).with_dpl_p3(true))
+            .with_rx_pw_p2(registers::RxPwP2::new().with_rx_pw_p2(33));
+        let errors = radio.validate(0).unwrap_err();
+        let expected = [
+            ConfigError::DynamicPayloadNotEnabled {
+                pipe: fields::Pipe::P0,
+            },
+            ConfigError::DynamicPayloadNotEnabled {
+                pipe: fields::Pipe::P3,
+            },
+            ConfigError::DynamicPayloadWithoutAutoAck {
+                pipe: fields::Pipe::P3,
+            },
+            ConfigError::IllegalAddressWidth,
+            ConfigError::PayloadWidthTooLarge {
+                pipe: fields::Pipe::P2,
+                width: 33,
+            },
+        ];
+        assert!(errors.iter().eq(expected));
+        assert_eq!(errors.get(4).unwrap().register(), "RX_PW_P2");
+        assert_eq!(errors.get(4).unwrap().field(), "rx_pw_p2");
+
+        let radio = RadioConfig::<4>::new()
+            .with_setup_aw(registers::SetupAw::new().with_aw(fields::AddressWidth::FiveByte));
+        let errors = radio.validate(0).unwrap_err();
+        assert!(errors.contains(&ConfigError::AddressWidthMismatch {
+            aw: fields::AddressWidth::FiveByte,
+            width: 4
+        }));
+    }
+
+    #[test]
+    fn test_validate_retransmit_delay() {
+        let feature = registers::Feature::new()
+            .with_en_dpl(true)
+            .with_en_ack_pay(true);
+        let radio = RadioConfig::<5>::new()
+            .with_feature(feature)
+            .with_dynpd(registers::Dynpd::new().with_dpl_p0(true));
+
+        // 2Mbps: 250µs fits 15 bytes
+        assert_eq!(radio.validate(15), Ok(()));
+        let errors = radio.validate(16).unwrap_err();
+        assert_eq!(
+            errors.get(0),
+            Some(ConfigError::RetransmitDelayTooShort {
+                ard: fields::AutoRetransmitDelay::US250,
+                min: fields::AutoRetransmitDelay::US500,
+            })
+        );
+
+        // 1Mbps: 250µs fits 5 bytes
+        let radio =
+            radio.with_rf_setup(registers::RfSetup::new().with_rf_dr_high(fields::RfDrHigh::Mbps1));
+        assert_eq!(radio.validate(5), Ok(()));
+        assert!(radio.validate(6).is_err());
+
+        // 250kbps: 500µs minimum, 250µs per 8 bytes of ACK payload above
+        let radio = radio.with_rf_setup(registers::RfSetup::new().with_rf_dr_low(true));
+        let retr = registers::SetupRetr::new();
+        let radio = radio.with_setup_retr(retr.with_ard(fields::AutoRetransmitDelay::US1250));
+        assert_eq!(radio.validate(24), Ok(()));
+        assert!(radio.validate(25).is_err());
+        let radio = radio
+            .with_feature(registers::Feature::new())
+            .with_dynpd(registers::Dynpd::new());
+        let radio = radio.with_setup_retr(retr.with_ard(fields::AutoRetransmitDelay::US500));
+        assert_eq!(radio.validate(32), Ok(()));
+        let radio = radio.with_setup_retr(retr.with_ard(fields::AutoRetransmitDelay::US250));
+        assert!(radio.validate(0).is_err());
+    }
+
+    #[test]
+    fn test_address_width() {
+        let radio = RadioConfig::<3>::new()
+            .with_tx_addr(registers::TxAddr::<3>::new().with_tx_addr(0x123456))
+            .with_feature(registers::Feature::new().with_en_dpl(true))
+            .with_dynpd(registers::Dynpd::new().with_dpl_p0(true));
+        assert_eq!(radio.setup_aw().aw(), fields::AddressWidth::ThreeByte);
+        assert!(radio.feature().en_dpl());
+
+        let init = radio.init_sequence();
+        assert_eq!(init[0].as_slice(), &[0x23, 0b01]);
+        assert_eq!(init[6].as_slice(), &[0x2A, 0xE7, 0xE7, 0xE7]);
+        assert_eq!(init[12].as_slice(), &[0x30, 0x56, 0x34, 0x12]);
+        assert_eq!(init[19].as_slice(), &[0x3D, 0b0000_0100]);
+        assert_eq!(init[20].as_slice(), &[0x3C, 0b0000_0001]);
+    }
+}
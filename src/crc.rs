@@ -0,0 +1,203 @@
+//! CRC used by the nRF24L01+ over the address, packet control field and payload.
+//!
+//! The CRC is computed MSBit first over a bit stream that is not byte aligned, since the
+//! packet control field is 9 bits. [`Crc`] can be fed any number of bits at a time.
+//!
+//! | [`Crco`]            | Polynomial                   | Initial value |
+//! |---------------------|------------------------------|---------------|
+//! | [`Crco::OneByte`]   | `x^8 + x^2 + x + 1`          | `0xFF`        |
+//! | [`Crco::TwoByte`]   | `x^16 + x^12 + x^5 + 1`      | `0xFFFF`      |
+//!
+//! ## Example
+//! ```rust
+//! use nrf24l01_commands::{crc::Crc, esb, fields::Crco};
+//!
+//! // CRC-16/CCITT-FALSE check value
+//! assert_eq!(Crc::new(Crco::TwoByte).update(b"123456789").finish(), 0x29B1);
+//!
+//! // CRC of an ESB packet with address 0xE7E7E7E7E7 and payload [1, 2, 3], over the 9 bit PCF
+//! let pcf = esb::Pcf::new().with_payload_length(3).with_pid(1);
+//! const ADDRESS: [u8; 5] = [0xE7; 5];
+//!
+//! let crc = Crc::new(Crco::TwoByte)
+//!     .update(&ADDRESS)
+//!     .update_bits(pcf.into_bits() as u32, 9)
+//!     .update(&[1, 2, 3])
+//!     .finish();
+//! // Sent MSByte first after the payload
+//! let crc_bytes = crc.to_be_bytes();
+//! ```
+use crate::fields::Crco;
+
+/// CRC-8 polynomial `x^8 + x^2 + x + 1`.
+pub const CRC8_POLY: u8 = 0x07;
+/// CRC-8 initial value.
+pub const CRC8_INIT: u8 = 0xFF;
+/// CRC-16 polynomial `x^16 + x^12 + x^5 + 1`.
+pub const CRC16_POLY: u16 = 0x1021;
+/// CRC-16 initial value.
+pub const CRC16_INIT: u16 = 0xFFFF;
+
+/// CRC state.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Crc {
+    crco: Crco,
+    value: u16,
+}
+
+impl Crc {
+    /// Create a CRC with the initial value of the encoding scheme.
+    pub const fn new(crco: Crco) -> Self {
+        let value = match crco {
+            Crco::OneByte => CRC8_INIT as u16,
+            Crco::TwoByte => CRC16_INIT,
+        };
+        Self { crco, value }
+    }
+
+    /// Feed a single bit.
+    pub const fn update_bit(self, bit: bool) -> Self {
+        let (width, poly) = match self.crco {
+            Crco::OneByte => (8, CRC8_POLY as u16),
+            Crco::TwoByte => (16, CRC16_POLY),
+        };
+        let top = self.value >> (width - 1) & 1 == 1;
+        let mut value = (self.value << 1) & (u16::MAX >> (16 - width));
+        if top != bit {
+            value ^= poly;
+        }
+        Self {
+            crco: self.crco,
+            value,
+        }
+    }
+
+    /// Feed the `len` LSBits of `value`, MSBit first. `len` is at most 32.
+    pub const fn update_bits(self, value: u32, len: u32) -> Self {
+        let mut crc = self;
+        let mut i = len;
+        while i > 0 {
+            i -= 1;
+            crc = crc.update_bit(value >> i & 1 == 1);
+        }
+        crc
+    }
+
+    /// Feed bytes, MSBit first.
+    pub const fn update(self, bytes: &[u8]) -> Self {
+        self.update_bit_range(bytes, 0, bytes.len() * 8)
+    }
+
+    /// Feed bits `start..end` of `bytes`, where bit 0 is the MSBit of `bytes[0]`.
+    pub const fn update_bit_range(self, bytes: &[u8], start: usize, end: usize) -> Self {
+        let mut crc = self;
+        let mut bit = start;
+        while bit < end {
+            crc = crc.update_bit(bytes[bit / 8] >> (7 - bit % 8) & 1 == 1);
+            bit += 1;
+        }
+        crc
+    }
+
+    /// CRC value. Only the LSByte is used for [`Crco::OneByte`].
+    pub const fn finish(self) -> u16 {
+        self.value
+    }
+}
+
+/// CRC-8 of `bytes`.
+pub const fn crc8(bytes: &[u8]) -> u8 {
+    Crc::new(Crco::OneByte).update(bytes).finish() as u8
+}
+
+/// CRC-16 of `bytes`.
+pub const fn crc16(bytes: &[u8]) -> u16 {
+    Crc::new(Crco::TwoByte).update(bytes).finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHECK: &[u8] = b"123456789";
+
+    #[test]
+    fn test_check_values() {
+        assert_eq!(crc8(CHECK), 0xFB);
+        assert_eq!(crc16(CHECK), 0x29B1);
+        assert_eq!(crc8(&[]), CRC8_INIT);
+        assert_eq!(crc16(&[]), CRC16_INIT);
+
+        const CRC16: u16 = crc16(CHECK);
+        assert_eq!(CRC16, 0x29B1);
+    }
+
+    #[test]
+    fn test_bit_granularity() {
+        // Feeding bits one at a time, in chunks or as a range gives the same CRC
+        let bytewise = Crc::new(Crco::TwoByte).update(CHECK);
+        let mut bitwise = Crc::new(Crco::TwoByte);
+        for &byte in CHECK {
+            bitwise = bitwise.update_bits((byte >> 4) as u32, 4);
+            bitwise = bitwise.update_bits((byte & 0xF) as u32, 4);
+        }
+        assert_eq!(bitwise, bytewise);
+
+        let range = Crc::new(Crco::TwoByte).update_bit_range(&[0xFF, 0x31, 0x80], 8, 17);
+        let bits = Crc::new(Crco::TwoByte).update_bits(0b0_0110_0011, 9);
+        assert_eq!(range, bits);
+    }
+
+    /// CRC-16 update of the `crc_update` routine in Bastille's nrf-research-firmware, which
+    /// validates ESB packets sniffed over the air in promiscuous mode. It feeds the frame
+    /// bit stream in 8 bit chunks and the bit left over by the 9 bit PCF on its own.
+    fn reference_crc_update(crc: u16, byte: u8, bits: u8) -> u16 {
+        let mut crc = crc ^ (byte as u16) << 8;
+        for _ in 0..bits {
+            crc = if crc & 0x8000 != 0 {
+                crc << 1 ^ 0x1021
+            } else {
+                crc << 1
+            };
+        }
+        crc
+    }
+
+    #[test]
+    fn test_esb_reference() {
+        // PCF layout from the nRF24L01+ product specification, section 7.3.3: 6 bit payload
+        // length, 2 bit PID and the NO_ACK flag, sent MSBit first after the address
+        let packets: [(&[u8], u8, bool, &[u8]); 3] = [
+            (&[0xE7; 5], 1, false, &[1, 2, 3]),
+            (&[0xE7; 5], 0, false, &[]),
+            (&[0x12, 0x34, 0x56, 0x78, 0x9A], 2, true, &[0xAB; 32]),
+        ];
+        for (address, pid, no_ack, payload) in packets {
+            let pcf = crate::esb::Pcf::new()
+                .with_payload_length(payload.len() as u8)
+                .with_pid(pid)
+                .with_no_ack(no_ack);
+            let crc = Crc::new(Crco::TwoByte)
+                .update(address)
+                .update_bits(pcf.into_bits() as u32, 9)
+                .update(payload)
+                .finish();
+
+            // The address, the first 8 PCF bits, then the payload shifted by the NO_ACK bit
+            let mut stream = [0; 5 + 1 + 33];
+            stream[..5].copy_from_slice(address);
+            stream[5] = (pcf.into_bits() >> 1) as u8;
+            let mut carry = (no_ack as u8) << 7;
+            for (i, &byte) in payload.iter().enumerate() {
+                stream[6 + i] = carry | byte >> 1;
+                carry = byte << 7;
+            }
+            let mut reference = 0xFFFF;
+            for &byte in &stream[..6 + payload.len()] {
+                reference = reference_crc_update(reference, byte, 8);
+            }
+            reference = reference_crc_update(reference, carry & 0x80, 1);
+            assert_eq!(crc, reference);
+        }
+    }
+}
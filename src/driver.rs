@@ -0,0 +1,632 @@
+//! Blocking driver for the nRF24L01+ with typestate power modes.
+//!
+//! Requires the `driver` feature.
+//!
+//! The driver models the chip's operational modes as typestates. Transitions between
+//! modes drive `pwr_up` and `prim_rx` in the [`CONFIG`][registers::Config] register and the CE pin,
+//! so commands are only available in the modes they are valid in:
+//!
+//! | Mode                      | CE   | `pwr_up` | `prim_rx` | Available commands                           |
+//! |---------------------------|------|----------|-----------|----------------------------------------------|
+//! | [`PowerDown`]             | low  | 0        | -         | register writes                              |
+//! | [`StandbyI`]              | low  | 1        | -         | register writes, TX payloads                 |
+//! | [`StandbyII`]             | high | 1        | 0         | TX payloads                                  |
+//! | [`Tx`]                    | high | 1        | 0         | TX payloads                                  |
+//! | [`Rx`]                    | high | 1        | 1         | RX payloads, ACK payloads                    |
+//!
+//! Register reads, RX FIFO reads and [`NOP`][commands::Nop] are available in every mode.
+//!
+//! ## Example
+//! ```rust
+//! use embedded_hal_mock::eh1::delay::NoopDelay;
+//! use embedded_hal_mock::eh1::digital::{Mock as PinMock, State, Transaction as PinTransaction};
+//! use embedded_hal_mock::eh1::spi::{Mock as SpiMock, Transaction as SpiTransaction};
+//! use nrf24l01_commands::{driver::Nrf24l01, registers};
+//!
+//! let spi = SpiMock::new(&[
+//!     // Initialize CONFIG in power down
+//!     SpiTransaction::transaction_start(),
+//!     SpiTransaction::transfer_in_place(vec![0b0010_0000, 0b0000_1000], vec![0b0000_1110, 0]),
+//!     SpiTransaction::transaction_end(),
+//!     // Power up
+//!     SpiTransaction::transaction_start(),
+//!     SpiTransaction::transfer_in_place(vec![0b0010_0000, 0b0000_1010], vec![0b0000_1110, 0]),
+//!     SpiTransaction::transaction_end(),
+//!     // Write TX payload
+//!     SpiTransaction::transaction_start(),
+//!     SpiTransaction::transfer(vec![0b1010_0000], vec![0b0000_1110]),
+//!     SpiTransaction::write_vec(vec![1, 2, 3]),
+//!     SpiTransaction::transaction_end(),
+//! ]);
+//! let ce = PinMock::new(&[
+//!     PinTransaction::set(State::Low),
+//!     // Pulse CE to transmit
+//!     PinTransaction::set(State::High),
+//!     PinTransaction::set(State::Low),
+//! ]);
+//!
+//! let radio = Nrf24l01::new(spi, ce, NoopDelay::new(), registers::Config::new()).unwrap();
+//! let mut radio = radio.power_up().map_err(|(_, e)| e).unwrap();
+//! radio.transmit([1, 2, 3]).unwrap();
+//!
+//! let (mut spi, mut ce, _) = radio.release();
+//! spi.done();
+//! ce.done();
+//! ```
+use crate::commands::{self, RegisterResponse};
+use crate::spi::Execute;
+use crate::timing::{THCE_US, TPD2STBY_US, TSTBY2A_US};
+use crate::{fields, registers};
+use core::marker::PhantomData;
+use embedded_hal::delay::DelayNs;
+use embedded_hal::digital::OutputPin;
+use embedded_hal::spi::SpiDevice;
+
+/// Driver error.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Error<SpiE, CeE> {
+    /// SPI error.
+    Spi(SpiE),
+    /// CE pin error.
+    Ce(CeE),
+}
+
+/// Driver error for the SPI device `SPI` and CE pin `CE`.
+pub type DriverError<SPI, CE> = Error<
+    <SPI as embedded_hal::spi::ErrorType>::Error,
+    <CE as embedded_hal::digital::ErrorType>::Error,
+>;
+
+/// Result of a transition from mode `M` to mode `Next`.
+/// On error, the driver is returned in mode `M`.
+pub type Transition<SPI, CE, D, M, Next> =
+    Result<Nrf24l01<SPI, CE, D, Next>, (Nrf24l01<SPI, CE, D, M>, DriverError<SPI, CE>)>;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Chip operational mode.
+pub trait Mode: sealed::Sealed {}
+
+/// Modes in which registers can be written: [`PowerDown`] and [`StandbyI`].
+pub trait Configurable: Mode {}
+
+/// Modes in which TX payloads can be written: [`StandbyI`], [`StandbyII`] and [`Tx`].
+pub trait TxCapable: Mode {}
+
+/// Power down mode. Registers are retained and can be written.
+pub struct PowerDown;
+/// Standby-I mode. Powered up with CE low.
+pub struct StandbyI;
+/// Standby-II mode. Primary TX with CE high and an empty TX FIFO.
+pub struct StandbyII;
+/// TX mode. Primary TX with CE high, transmitting from the TX FIFO.
+pub struct Tx;
+/// RX mode. Primary RX with CE high.
+pub struct Rx;
+
+impl sealed::Sealed for PowerDown {}
+impl sealed::Sealed for StandbyI {}
+impl sealed::Sealed for StandbyII {}
+impl sealed::Sealed for Tx {}
+impl sealed::Sealed for Rx {}
+impl Mode for PowerDown {}
+impl Mode for StandbyI {}
+impl Mode for StandbyII {}
+impl Mode for Tx {}
+impl Mode for Rx {}
+impl Configurable for PowerDown {}
+impl Configurable for StandbyI {}
+impl TxCapable for StandbyI {}
+impl TxCapable for StandbyII {}
+impl TxCapable for Tx {}
+
+/// nRF24L01+ driver.
+///
+/// #### Type Parameters
+/// - `SPI`: SPI device.
+/// - `CE`: CE output pin.
+/// - `D`: delay provider.
+/// - `M`: current [`Mode`].
+pub struct Nrf24l01<SPI, CE, D, M> {
+    spi: SPI,
+    ce: CE,
+    delay: D,
+    config: registers::Config,
+    mode: PhantomData<M>,
+}
+
+impl<SPI, CE, D> Nrf24l01<SPI, CE, D, PowerDown>
+where
+    SPI: SpiDevice,
+    CE: OutputPin,
+    D: DelayNs,
+{
+    /// Create the driver in [`PowerDown`] mode.
+    ///
+    /// Sets CE low and writes `config` to the [`CONFIG`][registers::Config] register
+    /// with `pwr_up` and `prim_rx` cleared.
+    pub fn new(
+        spi: SPI,
+        ce: CE,
+        delay: D,
+        config: registers::Config,
+    ) -> Result<Self, DriverError<SPI, CE>> {
+        let mut radio = Self {
+            spi,
+            ce,
+            delay,
+            config: config.with_pwr_up(false).with_prim_rx(false),
+            mode: PhantomData,
+        };
+        radio.ce.set_low().map_err(Error::Ce)?;
+        radio.write_config(radio.config)?;
+        Ok(radio)
+    }
+
+    /// Power up into [`StandbyI`] mode. Waits for the crystal oscillator to start up.
+    pub fn power_up(mut self) -> Transition<SPI, CE, D, PowerDown, StandbyI> {
+        if let Err(e) = self.write_config(self.config.with_pwr_up(true)) {
+            return Err((self, e));
+        }
+        self.delay.delay_us(TPD2STBY_US);
+        Ok(self.into_mode())
+    }
+}
+
+impl<SPI, CE, D> Nrf24l01<SPI, CE, D, StandbyI>
+where
+    SPI: SpiDevice,
+    CE: OutputPin,
+    D: DelayNs,
+{
+    /// Power down into [`PowerDown`] mode.
+    pub fn power_down(mut self) -> Transition<SPI, CE, D, StandbyI, PowerDown> {
+        match self.write_config(self.config.with_pwr_up(false)) {
+            Ok(_) => Ok(self.into_mode()),
+            Err(e) => Err((self, e)),
+        }
+    }
+
+    /// Enter [`Rx`] mode. Sets `prim_rx`, sets CE high and waits for the RX settling time.
+    pub fn start_rx(mut self) -> Transition<SPI, CE, D, StandbyI, Rx> {
+        if let Err(e) = self.set_prim_rx(true) {
+            return Err((self, e));
+        }
+        if let Err(e) = self.ce.set_high() {
+            return Err((self, Error::Ce(e)));
+        }
+        self.delay.delay_us(TSTBY2A_US);
+        Ok(self.into_mode())
+    }
+
+    /// Enter [`Tx`] mode. Clears `prim_rx`, sets CE high and waits for the TX settling time.
+    /// Packets in the TX FIFO are transmitted until it is empty, after which the chip
+    /// idles in Standby-II.
+    pub fn start_tx(mut self) -> Transition<SPI, CE, D, StandbyI, Tx> {
+        if let Err(e) = self.set_prim_rx(false) {
+            return Err((self, e));
+        }
+        if let Err(e) = self.ce.set_high() {
+            return Err((self, Error::Ce(e)));
+        }
+        self.delay.delay_us(TSTBY2A_US);
+        Ok(self.into_mode())
+    }
+
+    /// Write a TX payload and pulse CE to transmit it. The chip returns to Standby-I
+    /// after the packet is sent.
+    pub fn transmit<const N: usize>(
+        &mut self,
+        payload: [u8; N],
+    ) -> Result<registers::Status, DriverError<SPI, CE>> {
+        self.set_prim_rx(false)?;
+        let status = self.execute(commands::WTxPayload(payload))?;
+        self.ce.set_high().map_err(Error::Ce)?;
+        self.delay.delay_us(THCE_US);
+        self.ce.set_low().map_err(Error::Ce)?;
+        Ok(status)
+    }
+}
+
+impl<SPI, CE, D> Nrf24l01<SPI, CE, D, Tx>
+where
+    SPI: SpiDevice,
+    CE: OutputPin,
+    D: DelayNs,
+{
+    /// Enter [`StandbyII`] mode if the TX FIFO is empty, otherwise stay in [`Tx`] mode.
+    #[allow(clippy::type_complexity)]
+    pub fn try_into_standby_ii(
+        mut self,
+    ) -> Result<Result<Nrf24l01<SPI, CE, D, StandbyII>, Self>, (Self, DriverError<SPI, CE>)> {
+        match self.read_register::<registers::FifoStatus>() {
+            Ok(fifo_status) if fifo_status.tx_empty() => Ok(Ok(self.into_mode())),
+            Ok(_) => Ok(Err(self)),
+            Err(e) => Err((self, e)),
+        }
+    }
+
+    /// Set CE low and enter [`StandbyI`] mode. Transmission of the current packet is completed.
+    pub fn stop_tx(self) -> Transition<SPI, CE, D, Tx, StandbyI> {
+        self.into_standby_i()
+    }
+}
+
+impl<SPI, CE, D> Nrf24l01<SPI, CE, D, StandbyII>
+where
+    SPI: SpiDevice,
+    CE: OutputPin,
+    D: DelayNs,
+{
+    /// Write a TX payload and enter [`Tx`] mode to transmit it.
+    pub fn send<const N: usize>(
+        mut self,
+        payload: [u8; N],
+    ) -> Transition<SPI, CE, D, StandbyII, Tx> {
+        match self.execute(commands::WTxPayload(payload)) {
+            Ok(_) => Ok(self.into_mode()),
+            Err(e) => Err((self, e)),
+        }
+    }
+
+    /// Set CE low and enter [`StandbyI`] mode.
+    pub fn stop_tx(self) -> Transition<SPI, CE, D, StandbyII, StandbyI> {
+        self.into_standby_i()
+    }
+}
+
+impl<SPI, CE, D> Nrf24l01<SPI, CE, D, Rx>
+where
+    SPI: SpiDevice,
+    CE: OutputPin,
+    D: DelayNs,
+{
+    /// Set CE low and enter [`StandbyI`] mode.
+    pub fn stop_rx(self) -> Transition<SPI, CE, D, Rx, StandbyI> {
+        self.into_standby_i()
+    }
+
+    /// Write a payload to be sent with the ACK packet on a data pipe.
+    pub fn write_ack_payload<const N: usize>(
+        &mut self,
+        pipe: fields::Pipe,
+        payload: [u8; N],
+    ) -> Result<registers::Status, DriverError<SPI, CE>> {
+        self.execute(commands::WAckPayload { pipe, payload })
+    }
+}
+
+impl<SPI, CE, D, M> Nrf24l01<SPI, CE, D, M>
+where
+    SPI: SpiDevice,
+    CE: OutputPin,
+    D: DelayNs,
+    M: Configurable,
+{
+    /// Write a register.
+    ///
+    /// <div class="warning">
+    /// Use <code>set_config</code> to write the CONFIG register, so that
+    /// <code>pwr_up</code> and <code>prim_rx</code> stay consistent with the current mode.
+    /// </div>
+    pub fn write_register<R>(
+        &mut self,
+        register: R,
+    ) -> Result<registers::Status, DriverError<SPI, CE>>
+    where
+        commands::WRegister<R>: Execute<Response = registers::Status>,
+    {
+        self.execute(commands::WRegister(register))
+    }
+
+    /// Write the [`CONFIG`][registers::Config] register. `pwr_up` and `prim_rx` are
+    /// kept as required by the current mode.
+    pub fn set_config(
+        &mut self,
+        config: registers::Config,
+    ) -> Result<registers::Status, DriverError<SPI, CE>> {
+        let config = config
+            .with_pwr_up(self.config.pwr_up())
+            .with_prim_rx(self.config.prim_rx());
+        self.write_config(config)
+    }
+}
+
+impl<SPI, CE, D, M> Nrf24l01<SPI, CE, D, M>
+where
+    SPI: SpiDevice,
+    CE: OutputPin,
+    D: DelayNs,
+    M: TxCapable,
+{
+    /// Write a TX payload.
+    pub fn write_tx_payload<const N: usize>(
+        &mut self,
+        payload: [u8; N],
+    ) -> Result<registers::Status, DriverError<SPI, CE>> {
+        self.execute(commands::WTxPayload(payload))
+    }
+
+    /// Write a TX payload with AUTOACK disabled.
+    pub fn write_tx_payload_noack<const N: usize>(
+        &mut self,
+        payload: [u8; N],
+    ) -> Result<registers::Status, DriverError<SPI, CE>> {
+        self.execute(commands::WTxPayloadNoack(payload))
+    }
+
+    /// Reuse the last transmitted payload.
+    pub fn reuse_tx_payload(&mut self) -> Result<registers::Status, DriverError<SPI, CE>> {
+        self.execute(commands::ReuseTxPl())
+    }
+
+    /// Flush the TX FIFO.
+    pub fn flush_tx(&mut self) -> Result<registers::Status, DriverError<SPI, CE>> {
+        self.execute(commands::FlushTx())
+    }
+}
+
+impl<SPI, CE, D, M> Nrf24l01<SPI, CE, D, M>
+where
+    SPI: SpiDevice,
+    CE: OutputPin,
+    D: DelayNs,
+    M: Mode,
+{
+    /// Read a register.
+    pub fn read_register<R>(&mut self) -> Result<R, DriverError<SPI, CE>>
+    where
+        commands::RRegister<R>: Execute<Response = RegisterResponse<R>>,
+    {
+        Ok(self.execute(commands::RRegister::<R>::new())?.register)
+    }
+
+    /// Read the [`STATUS`][registers::Status] register with [`NOP`][commands::Nop].
+    pub fn status(&mut self) -> Result<registers::Status, DriverError<SPI, CE>> {
+        self.execute(commands::Nop())
+    }
+
+    /// Clear the chosen interrupt flags, leaving the others set.
+    pub fn clear_interrupts(
+        &mut self,
+        command: commands::ClearInterrupts,
+    ) -> Result<registers::Status, DriverError<SPI, CE>> {
+        self.execute(command)
+    }
+
+    /// Read the width of the top payload in the RX FIFO.
+    pub fn rx_payload_width(&mut self) -> Result<u8, DriverError<SPI, CE>> {
+        Ok(self.execute(commands::RRxPlWid())?.width)
+    }
+
+    /// Read the top payload from the RX FIFO.
+    pub fn read_rx_payload<const N: usize>(
+        &mut self,
+    ) -> Result<commands::RxPayloadResponse<N>, DriverError<SPI, CE>> {
+        self.execute(commands::RRxPayload::<N>())
+    }
+
+    /// Flush the RX FIFO.
+    pub fn flush_rx(&mut self) -> Result<registers::Status, DriverError<SPI, CE>> {
+        self.execute(commands::FlushRx())
+    }
+
+    /// Cached value of the [`CONFIG`][registers::Config] register.
+    pub fn config(&self) -> registers::Config {
+        self.config
+    }
+
+    /// Release the SPI device, CE pin and delay provider.
+    pub fn release(self) -> (SPI, CE, D) {
+        (self.spi, self.ce, self.delay)
+    }
+
+    fn execute<C: Execute>(&mut self, command: C) -> Result<C::Response, DriverError<SPI, CE>> {
+        command.execute(&mut self.spi).map_err(Error::Spi)
+    }
+
+    fn write_config(
+        &mut self,
+        config: registers::Config,
+    ) -> Result<registers::Status, DriverError<SPI, CE>> {
+        let status = self.execute(commands::WRegister(config))?;
+        self.config = config;
+        Ok(status)
+    }
+
+    fn set_prim_rx(&mut self, prim_rx: bool) -> Result<(), DriverError<SPI, CE>> {
+        if self.config.prim_rx() != prim_rx {
+            self.write_config(self.config.with_prim_rx(prim_rx))?;
+        }
+        Ok(())
+    }
+
+    fn into_standby_i(mut self) -> Transition<SPI, CE, D, M, StandbyI> {
+        match self.ce.set_low() {
+            Ok(()) => Ok(self.into_mode()),
+            Err(e) => Err((self, Error::Ce(e))),
+        }
+    }
+
+    fn into_mode<N: Mode>(self) -> Nrf24l01<SPI, CE, D, N> {
+        Nrf24l01 {
+            spi: self.spi,
+            ce: self.ce,
+            delay: self.delay,
+            config: self.config,
+            mode: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use embedded_hal_mock::eh1::delay::{CheckedDelay, Transaction as DelayTransaction};
+    use embedded_hal_mock::eh1::digital::{Mock as PinMock, State, Transaction as PinTransaction};
+    use embedded_hal_mock::eh1::spi::{Mock as SpiMock, Transaction as SpiTransaction};
+    use std::{vec, vec::Vec};
+
+    const STATUS: u8 = 0b0000_1110;
+
+    fn write_config(config: u8) -> Vec<SpiTransaction<u8>> {
+        vec![
+            SpiTransaction::transaction_start(),
+            SpiTransaction::transfer_in_place(vec![0b0010_0000, config], vec![STATUS, 0]),
+            SpiTransaction::transaction_end(),
+        ]
+    }
+
+    #[test]
+    fn test_rx_cycle() {
+        let spi = SpiMock::new(
+            &[
+                write_config(0b0000_1000),
+                write_config(0b0000_1010),
+                write_config(0b0000_1011),
+                vec![
+                    SpiTransaction::transaction_start(),
+                    SpiTransaction::transfer(vec![0b0110_0001], vec![0b0100_0000]),
+                    SpiTransaction::read_vec(vec![1, 2]),
+                    SpiTransaction::transaction_end(),
+                ],
+                write_config(0b0000_1001),
+            ]
+            .concat(),
+        );
+        let ce = PinMock::new(&[
+            PinTransaction::set(State::Low),
+            PinTransaction::set(State::High),
+            PinTransaction::set(State::Low),
+        ]);
+        let delay = CheckedDelay::new(&[
+            DelayTransaction::delay_us(TPD2STBY_US),
+            DelayTransaction::delay_us(TSTBY2A_US),
+        ]);
+
+        let radio = Nrf24l01::new(spi, ce, delay, registers::Config::new()).unwrap();
+        let radio = radio.power_up().map_err(|(_, e)| e).unwrap();
+        let mut radio = radio.start_rx().map_err(|(_, e)| e).unwrap();
+        assert!(radio.config().prim_rx());
+
+        let response = radio.read_rx_payload::<2>().unwrap();
+        assert!(response.status.rx_dr());
+        assert_eq!(response.payload, [1, 2]);
+
+        let radio = radio.stop_rx().map_err(|(_, e)| e).unwrap();
+        let radio = radio.power_down().map_err(|(_, e)| e).unwrap();
+        assert!(!radio.config().pwr_up());
+
+        let (mut spi, mut ce, mut delay) = radio.release();
+        spi.done();
+        ce.done();
+        delay.done();
+    }
+
+    #[test]
+    fn test_tx_cycle() {
+        let spi = SpiMock::new(
+            &[
+                write_config(0b0000_1000),
+                write_config(0b0000_1010),
+                vec![
+                    // Fill TX FIFO in Standby-I
+                    SpiTransaction::transaction_start(),
+                    SpiTransaction::transfer(vec![0b1010_0000], vec![STATUS]),
+                    SpiTransaction::write_vec(vec![1, 2, 3]),
+                    SpiTransaction::transaction_end(),
+                    // TX FIFO not empty
+                    SpiTransaction::transaction_start(),
+                    SpiTransaction::transfer_in_place(vec![0x17, 0], vec![STATUS, 0b0000_0001]),
+                    SpiTransaction::transaction_end(),
+                    // TX FIFO empty
+                    SpiTransaction::transaction_start(),
+                    SpiTransaction::transfer_in_place(vec![0x17, 0], vec![STATUS, 0b0001_0001]),
+                    SpiTransaction::transaction_end(),
+                    // Send from Standby-II
+                    SpiTransaction::transaction_start(),
+                    SpiTransaction::transfer(vec![0b1010_0000], vec![STATUS]),
+                    SpiTransaction::write_vec(vec![4]),
+                    SpiTransaction::transaction_end(),
+                ],
+            ]
+            .concat(),
+        );
+        let ce = PinMock::new(&[
+            PinTransaction::set(State::Low),
+            PinTransaction::set(State::High),
+            PinTransaction::set(State::Low),
+        ]);
+        let delay = CheckedDelay::new(&[
+            DelayTransaction::delay_us(TPD2STBY_US),
+            DelayTransaction::delay_us(TSTBY2A_US),
+        ]);
+
+        let radio = Nrf24l01::new(spi, ce, delay, registers::Config::new()).unwrap();
+        let mut radio = radio.power_up().map_err(|(_, e)| e).unwrap();
+        radio.write_tx_payload([1, 2, 3]).unwrap();
+        let radio = radio.start_tx().map_err(|(_, e)| e).unwrap();
+        let radio = radio.try_into_standby_ii().map_err(|(_, e)| e).unwrap();
+        let Err(radio) = radio else {
+            panic!("TX FIFO is not empty")
+        };
+        let radio = radio.try_into_standby_ii().map_err(|(_, e)| e).unwrap();
+        let Ok(radio) = radio else {
+            panic!("TX FIFO is empty")
+        };
+        let radio = radio.send([4]).map_err(|(_, e)| e).unwrap();
+        let radio = radio.stop_tx().map_err(|(_, e)| e).unwrap();
+
+        let (mut spi, mut ce, mut delay) = radio.release();
+        spi.done();
+        ce.done();
+        delay.done();
+    }
+
+    #[test]
+    fn test_transmit_pulse() {
+        let spi = SpiMock::new(
+            &[
+                write_config(0b0000_1000),
+                write_config(0b0000_1010),
+                write_config(0b0000_1011),
+                write_config(0b0000_1010),
+                vec![
+                    SpiTransaction::transaction_start(),
+                    SpiTransaction::transfer(vec![0b1010_0000], vec![STATUS]),
+                    SpiTransaction::write_vec(vec![9]),
+                    SpiTransaction::transaction_end(),
+                ],
+            ]
+            .concat(),
+        );
+        let ce = PinMock::new(&[
+            PinTransaction::set(State::Low),
+            PinTransaction::set(State::High),
+            PinTransaction::set(State::Low),
+            PinTransaction::set(State::High),
+            PinTransaction::set(State::Low),
+        ]);
+        let delay = CheckedDelay::new(&[
+            DelayTransaction::delay_us(TPD2STBY_US),
+            DelayTransaction::delay_us(TSTBY2A_US),
+            DelayTransaction::delay_us(THCE_US),
+        ]);
+
+        let radio = Nrf24l01::new(spi, ce, delay, registers::Config::new()).unwrap();
+        let radio = radio.power_up().map_err(|(_, e)| e).unwrap();
+        let radio = radio.start_rx().map_err(|(_, e)| e).unwrap();
+        let mut radio = radio.stop_rx().map_err(|(_, e)| e).unwrap();
+        // Transmitting from Standby-I clears prim_rx first
+        radio.transmit([9]).unwrap();
+
+        let (mut spi, mut ce, mut delay) = radio.release();
+        spi.done();
+        ce.done();
+        delay.done();
+    }
+}
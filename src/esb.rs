@@ -0,0 +1,381 @@
+//! Enhanced ShockBurst on-air frame encoder and decoder.
+//!
+//! An ESB frame is transmitted MSBit first and consists of:
+//!
+//! | Field                  | Length      |
+//! |------------------------|-------------|
+//! | Preamble               | 8 bits      |
+//! | Address                | 3-5 bytes   |
+//! | Packet control field   | 9 bits      |
+//! | Payload                | 0-32 bytes  |
+//! | CRC                    | 1-2 bytes   |
+//!
+//! The address is sent MSByte first, i.e. in reverse order of the address register bytes
+//! written over SPI. The preamble is `0xAA` if the first address bit is `1`, otherwise `0x55`.
+//! The CRC covers the address, packet control field and payload, see [`crc`](crate::crc).
+//!
+//! Frames are stored MSBit first in bytes. The last byte is padded with `0` bits.
+//!
+//! ## Example
+//! ```rust
+//! use nrf24l01_commands::{esb, fields};
+//!
+//! let format = esb::Format {
+//!     address_width: fields::AddressWidth::FiveByte,
+//!     crc: fields::Crco::TwoByte,
+//! };
+//! let packet = esb::Packet::new(0xE7E7E7E7E7, 1, false, &[1, 2, 3]).unwrap();
+//!
+//! let frame = esb::encode(&format, &packet).unwrap();
+//! assert_eq!(frame.bit_len(), 97);
+//! assert_eq!(
+//!     frame.as_bytes(),
+//!     &[0xAA, 0xE7, 0xE7, 0xE7, 0xE7, 0xE7, 0x0D, 0x00, 0x81, 0x01, 0xCE, 0x77, 0x80]
+//! );
+//!
+//! let decoded = esb::decode(&format, frame.as_bytes()).unwrap();
+//! assert_eq!(decoded, packet);
+//! ```
+use crate::crc::Crc;
+use crate::fields;
+use bitfield_struct::bitfield;
+
+/// Maximum payload length in bytes.
+pub const MAX_PAYLOAD_LEN: usize = 32;
+/// Maximum frame length in bits, with a 5 byte address, 32 byte payload and 2 byte CRC.
+pub const MAX_FRAME_BITS: usize = 8 + 5 * 8 + 9 + MAX_PAYLOAD_LEN * 8 + 2 * 8;
+/// Maximum frame length in bytes.
+pub const MAX_FRAME_LEN: usize = MAX_FRAME_BITS.div_ceil(8);
+
+/// Preamble if the first address bit is `1`.
+const PREAMBLE_1: u8 = 0xAA;
+/// Preamble if the first address bit is `0`.
+const PREAMBLE_0: u8 = 0x55;
+
+/// ESB frame error.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// Payload is longer than 32 bytes.
+    PayloadTooLong,
+    /// Address width is [`AddressWidth::Illegal`](fields::AddressWidth::Illegal).
+    IllegalAddressWidth,
+    /// Frame is shorter than its packet control field indicates.
+    FrameTooShort,
+    /// Preamble does not match the first address bit.
+    Preamble,
+    /// CRC mismatch.
+    Crc,
+}
+
+/// Frame format, from the [`SETUP_AW`](crate::registers::SetupAw) and
+/// [`CONFIG`](crate::registers::Config) registers.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Format {
+    /// Address width.
+    pub address_width: fields::AddressWidth,
+    /// CRC length.
+    pub crc: fields::Crco,
+}
+
+impl Format {
+    /// Address width in bytes.
+    pub const fn address_len(&self) -> Result<usize, Error> {
+        match self.address_width {
+            fields::AddressWidth::Illegal => Err(Error::IllegalAddressWidth),
+            fields::AddressWidth::ThreeByte => Ok(3),
+            fields::AddressWidth::FourByte => Ok(4),
+            fields::AddressWidth::FiveByte => Ok(5),
+        }
+    }
+
+    /// CRC length in bytes.
+    pub const fn crc_len(&self) -> usize {
+        match self.crc {
+            fields::Crco::OneByte => 1,
+            fields::Crco::TwoByte => 2,
+        }
+    }
+}
+
+/// # Packet control field
+/// 9-bit field sent after the address.
+///
+/// ## Fields
+///
+/// #### `payload_length` | bits 8:3
+/// Payload length in bytes, 0-32.
+///
+/// #### `pid` | bits 2:1
+/// Packet identity. Incremented for every new packet, used to detect retransmits.
+///
+/// #### `no_ack` | bit 0
+/// The receiver must not send an ACK.
+///
+/// ## Example
+/// ```rust
+/// use nrf24l01_commands::esb;
+///
+/// let pcf = esb::Pcf::new()
+///     .with_payload_length(3)
+///     .with_pid(1)
+///     .with_no_ack(false);
+/// assert_eq!(pcf.into_bits(), 0b0_0000_0001_1010);
+/// ```
+#[bitfield(u16, order = Msb)]
+#[derive(PartialEq, Eq)]
+pub struct Pcf {
+    #[bits(7)]
+    __: u8,
+
+    /// Payload length in bytes, 0-32.
+    #[bits(6)]
+    pub payload_length: u8,
+
+    /// Packet identity.
+    #[bits(2)]
+    pub pid: u8,
+
+    /// The receiver must not send an ACK.
+    #[bits(1)]
+    pub no_ack: bool,
+}
+
+/// An ESB packet.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Packet {
+    /// Address, with the same value as the address registers.
+    pub address: u64,
+    pcf: Pcf,
+    payload: [u8; MAX_PAYLOAD_LEN],
+}
+
+impl Packet {
+    /// Create a packet. `pid` is truncated to 2 bits.
+    pub const fn new(address: u64, pid: u8, no_ack: bool, payload: &[u8]) -> Result<Self, Error> {
+        if payload.len() > MAX_PAYLOAD_LEN {
+            return Err(Error::PayloadTooLong);
+        }
+        let mut buf = [0; MAX_PAYLOAD_LEN];
+        let mut i = 0;
+        while i < payload.len() {
+            buf[i] = payload[i];
+            i += 1;
+        }
+        Ok(Self {
+            address,
+            pcf: Pcf::new()
+                .with_payload_length(payload.len() as u8)
+                .with_pid(pid & 0b11)
+                .with_no_ack(no_ack),
+            payload: buf,
+        })
+    }
+
+    /// Packet control field.
+    pub const fn pcf(&self) -> Pcf {
+        self.pcf
+    }
+
+    /// Payload bytes.
+    pub fn payload(&self) -> &[u8] {
+        &self.payload[..self.pcf.payload_length() as usize]
+    }
+}
+
+/// An encoded ESB frame.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Frame {
+    bytes: [u8; MAX_FRAME_LEN],
+    bits: usize,
+}
+
+impl Frame {
+    /// Frame bytes, MSBit first. The last byte is padded with `0` bits.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.bits.div_ceil(8)]
+    }
+
+    /// Frame length in bits.
+    pub const fn bit_len(&self) -> usize {
+        self.bits
+    }
+}
+
+/// Encode a packet into an on-air frame.
+pub fn encode(format: &Format, packet: &Packet) -> Result<Frame, Error> {
+    let address_len = format.address_len()?;
+    let payload_len = packet.pcf.payload_length() as usize;
+    if payload_len > MAX_PAYLOAD_LEN {
+        return Err(Error::PayloadTooLong);
+    }
+
+    let mut frame = Frame {
+        bytes: [0; MAX_FRAME_LEN],
+        bits: 0,
+    };
+    let first_bit = packet.address >> (address_len * 8 - 1) & 1;
+    let preamble = if first_bit == 1 {
+        PREAMBLE_1
+    } else {
+        PREAMBLE_0
+    };
+    frame.push(preamble as u32, 8);
+    for i in (0..address_len).rev() {
+        frame.push((packet.address >> (i * 8)) as u32 & 0xFF, 8);
+    }
+    frame.push(packet.pcf.into_bits() as u32, 9);
+    for &byte in packet.payload() {
+        frame.push(byte as u32, 8);
+    }
+    let crc = Crc::new(format.crc)
+        .update_bit_range(&frame.bytes, 8, frame.bits)
+        .finish();
+    frame.push(crc as u32, format.crc_len() * 8);
+    Ok(frame)
+}
+
+/// Decode an on-air frame, starting at the preamble. Bits after the CRC are ignored.
+pub fn decode(format: &Format, bytes: &[u8]) -> Result<Packet, Error> {
+    let address_len = format.address_len()?;
+    let header_bits = 8 + address_len * 8 + 9;
+    if bytes.len() * 8 < header_bits {
+        return Err(Error::FrameTooShort);
+    }
+
+    let mut address = 0;
+    for i in 0..address_len {
+        address = address << 8 | bytes[1 + i] as u64;
+    }
+    let expected = if bytes[1] & 0x80 != 0 {
+        PREAMBLE_1
+    } else {
+        PREAMBLE_0
+    };
+    if bytes[0] != expected {
+        return Err(Error::Preamble);
+    }
+
+    let pcf = Pcf::from_bits(read_bits(bytes, header_bits - 9, 9) as u16);
+    let payload_len = pcf.payload_length() as usize;
+    if payload_len > MAX_PAYLOAD_LEN {
+        return Err(Error::PayloadTooLong);
+    }
+    let crc_bits = format.crc_len() * 8;
+    let crc_start = header_bits + payload_len * 8;
+    if bytes.len() * 8 < crc_start + crc_bits {
+        return Err(Error::FrameTooShort);
+    }
+    let crc = Crc::new(format.crc)
+        .update_bit_range(bytes, 8, crc_start)
+        .finish();
+    if crc != read_bits(bytes, crc_start, crc_bits) as u16 {
+        return Err(Error::Crc);
+    }
+
+    let mut payload = [0; MAX_PAYLOAD_LEN];
+    for (i, byte) in payload.iter_mut().enumerate().take(payload_len) {
+        *byte = read_bits(bytes, header_bits + i * 8, 8) as u8;
+    }
+    Ok(Packet {
+        address,
+        pcf,
+        payload,
+    })
+}
+
+impl Frame {
+    /// Append the `len` LSBits of `value`, MSBit first.
+    fn push(&mut self, value: u32, len: usize) {
+        for i in (0..len).rev() {
+            let bit = (value >> i & 1) as u8;
+            self.bytes[self.bits / 8] |= bit << (7 - self.bits % 8);
+            self.bits += 1;
+        }
+    }
+}
+
+/// Read `len` bits starting at bit `start`, MSBit first.
+fn read_bits(bytes: &[u8], start: usize, len: usize) -> u32 {
+    let mut value = 0;
+    for bit in start..start + len {
+        value = value << 1 | (bytes[bit / 8] >> (7 - bit % 8) & 1) as u32;
+    }
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_decode() {
+        let format = Format {
+            address_width: fields::AddressWidth::ThreeByte,
+            crc: fields::Crco::OneByte,
+        };
+        let packet = Packet::new(0x123456, 2, true, &[0xAB]).unwrap();
+
+        let frame = encode(&format, &packet).unwrap();
+        assert_eq!(frame.bit_len(), 57);
+        assert_eq!(
+            frame.as_bytes(),
+            &[0x55, 0x12, 0x34, 0x56, 0x06, 0xD5, 0xBF, 0x80]
+        );
+
+        let decoded = decode(&format, frame.as_bytes()).unwrap();
+        assert_eq!(decoded.address, 0x123456);
+        assert_eq!(decoded.pcf().pid(), 2);
+        assert!(decoded.pcf().no_ack());
+        assert_eq!(decoded.payload(), &[0xAB]);
+    }
+
+    #[test]
+    fn test_empty_payload() {
+        let format = Format {
+            address_width: fields::AddressWidth::FiveByte,
+            crc: fields::Crco::TwoByte,
+        };
+        let packet = Packet::new(0xE7E7E7E7E7, 0, false, &[]).unwrap();
+
+        let frame = encode(&format, &packet).unwrap();
+        assert_eq!(frame.bit_len(), 73);
+        assert_eq!(
+            frame.as_bytes(),
+            &[0xAA, 0xE7, 0xE7, 0xE7, 0xE7, 0xE7, 0x00, 0x68, 0xF2, 0x00]
+        );
+        assert_eq!(decode(&format, frame.as_bytes()).unwrap(), packet);
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        let format = Format {
+            address_width: fields::AddressWidth::ThreeByte,
+            crc: fields::Crco::OneByte,
+        };
+        let frame = [0x55, 0x12, 0x34, 0x56, 0x06, 0xD5, 0xBF, 0x80];
+
+        let mut corrupted = frame;
+        corrupted[5] ^= 0x10;
+        assert_eq!(decode(&format, &corrupted), Err(Error::Crc));
+        let mut corrupted = frame;
+        corrupted[0] = 0xAA;
+        assert_eq!(decode(&format, &corrupted), Err(Error::Preamble));
+        assert_eq!(decode(&format, &frame[..6]), Err(Error::FrameTooShort));
+        // PCF payload length of 63
+        let oversized = [0x55, 0x12, 0x34, 0x56, 0xFC, 0x00, 0x00, 0x00];
+        assert_eq!(decode(&format, &oversized), Err(Error::PayloadTooLong));
+        let mut packet = Packet::new(0x123456, 0, false, &[]).unwrap();
+        packet.pcf = packet.pcf.with_payload_length(63);
+        assert_eq!(encode(&format, &packet), Err(Error::PayloadTooLong));
+
+        let format = Format {
+            address_width: fields::AddressWidth::Illegal,
+            crc: fields::Crco::OneByte,
+        };
+        assert_eq!(decode(&format, &frame), Err(Error::IllegalAddressWidth));
+        assert_eq!(
+            Packet::new(0, 0, false, &[0; 33]),
+            Err(Error::PayloadTooLong)
+        );
+    }
+}
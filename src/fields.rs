@@ -1,7 +1,7 @@
 //! Enums for certain nRF24L01+ register fields.
 
 /// A trait for certain multi-bit register fields that are represented as enums.
-#[const_trait]
+#[cfg_attr(feature = "nightly", const_trait)]
 pub trait EnumField {
     /// Convert the field to its bits representation.
     fn into_bits(self) -> u8;
@@ -9,6 +9,96 @@ pub trait EnumField {
     fn from_bits(bits: u8) -> Self;
 }
 
+/// Bits that don't encode a valid value.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum InvalidBits {
+    /// The bits of a field are a reserved value, e.g. [`RxPipeNo::NotUsed`].
+    Value {
+        /// Name of the field's enum.
+        field: &'static str,
+        /// Bits of the field.
+        bits: u8,
+    },
+    /// Bits outside of any field are set.
+    Reserved {
+        /// The bits that are set.
+        bits: u8,
+    },
+}
+
+impl core::fmt::Display for InvalidBits {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Value { field, bits } => write!(f, "{field}: reserved value {bits:#b}"),
+            Self::Reserved { bits } => write!(f, "reserved bits {bits:#010b} are set"),
+        }
+    }
+}
+
+/// Fail with [`InvalidBits::Reserved`] if any bit of `bits` outside of `mask` is set.
+pub(crate) const fn check_reserved(bits: u8, mask: u8) -> Result<(), InvalidBits> {
+    match bits & !mask {
+        0 => Ok(()),
+        bits => Err(InvalidBits::Reserved { bits }),
+    }
+}
+
+/// Define the conversions as inherent `const fn`s, which are usable in `const` context on stable,
+/// and implement [`EnumField`] with them.
+///
+/// `try_from_bits` and [`TryFrom<u8>`] reject bits outside of the mask and the variants listed
+/// after `reserved`, which only exist to make `from_bits` sound.
+macro_rules! enum_field {
+    ($field:ident, $mask:literal $(, reserved: $($reserved:ident)|+)?) => {
+        impl $field {
+            /// Convert the field to its bits representation.
+            pub const fn into_bits(self) -> u8 {
+                self as _
+            }
+
+            /// Convert bits to the field.
+            pub const fn from_bits(bits: u8) -> Self {
+                // SAFETY: Every value of the masked bits is a variant of the `repr(u8)` enum
+                unsafe { core::mem::transmute(bits & $mask) }
+            }
+
+            /// Convert bits to the field, failing on bits outside of the field or a reserved
+            /// value.
+            pub const fn try_from_bits(bits: u8) -> Result<Self, InvalidBits> {
+                if let Err(e) = check_reserved(bits, $mask) {
+                    return Err(e);
+                }
+                match Self::from_bits(bits) {
+                    $($(Self::$reserved)|+ => Err(InvalidBits::Value {
+                        field: stringify!($field),
+                        bits,
+                    }),)?
+                    field => Ok(field),
+                }
+            }
+        }
+
+        impl TryFrom<u8> for $field {
+            type Error = InvalidBits;
+
+            fn try_from(bits: u8) -> Result<Self, InvalidBits> {
+                Self::try_from_bits(bits)
+            }
+        }
+
+        const_impl! {
+            impl EnumField for $field {
+                fn into_bits(self) -> u8 {
+                    self.into_bits()
+                }
+                fn from_bits(bits: u8) -> Self {
+                    Self::from_bits(bits)
+                }
+            }
+        }
+    };
+}
+
 /// CRC encoding scheme.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 #[repr(u8)]
@@ -18,15 +108,7 @@ pub enum Crco {
     /// 2 byte CRC
     TwoByte = 1,
 }
-impl const EnumField for Crco {
-    fn into_bits(self) -> u8 {
-        self as _
-    }
-    fn from_bits(bits: u8) -> Self {
-        // SAFETY: The result is guaranteed to be in the range of 0-1
-        unsafe { core::mem::transmute(bits & 1) }
-    }
-}
+enum_field!(Crco, 1);
 
 /// RX/TX address field width in bytes.
 /// LSByte is used if address width is below 5 bytes.
@@ -38,15 +120,7 @@ pub enum AddressWidth {
     FourByte = 0b10,
     FiveByte = 0b11,
 }
-impl const EnumField for AddressWidth {
-    fn into_bits(self) -> u8 {
-        self as _
-    }
-    fn from_bits(bits: u8) -> Self {
-        // SAFETY: The result is guaranteed to be in the range of 0-3
-        unsafe { core::mem::transmute(bits & 0b11) }
-    }
-}
+enum_field!(AddressWidth, 0b11, reserved: Illegal);
 
 /// Auto retransmit delay.
 ///
@@ -79,15 +153,7 @@ pub enum AutoRetransmitDelay {
     US3750 = 0b1110,
     US4000 = 0b1111,
 }
-impl const EnumField for AutoRetransmitDelay {
-    fn into_bits(self) -> u8 {
-        self as _
-    }
-    fn from_bits(bits: u8) -> Self {
-        // SAFETY: The result is guaranteed to be in the range of 0-15
-        unsafe { core::mem::transmute(bits & 0b1111) }
-    }
-}
+enum_field!(AutoRetransmitDelay, 0b1111);
 
 /// High speed data rate.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -96,14 +162,18 @@ pub enum RfDrHigh {
     Mbps1 = 0,
     Mbps2 = 1,
 }
-impl const EnumField for RfDrHigh {
-    fn into_bits(self) -> u8 {
-        self as _
-    }
-    fn from_bits(bits: u8) -> Self {
-        // SAFETY: The result is guaranteed to be in the range of 0-1
-        unsafe { core::mem::transmute(bits & 1) }
-    }
+enum_field!(RfDrHigh, 1);
+
+/// Air data rate, encoded by `rf_dr_low` and `rf_dr_high` of
+/// [`RF_SETUP`](crate::registers::RfSetup).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DataRate {
+    /// 250kbps, `rf_dr_low` set.
+    Kbps250,
+    /// 1Mbps, neither bit set.
+    Mbps1,
+    /// 2Mbps, `rf_dr_high` set.
+    Mbps2,
 }
 
 /// Set RF output power in TX mode.
@@ -119,15 +189,7 @@ pub enum RfPower {
     /// 0 dBm
     Dbm0 = 0b11,
 }
-impl const EnumField for RfPower {
-    fn into_bits(self) -> u8 {
-        self as _
-    }
-    fn from_bits(bits: u8) -> Self {
-        // SAFETY: The result is guaranteed to be in the range of 0-3
-        unsafe { core::mem::transmute(bits & 0b11) }
-    }
-}
+enum_field!(RfPower, 0b11);
 
 /// Data pipe number for the payload available from reading RX FIFO.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -142,12 +204,75 @@ pub enum RxPipeNo {
     NotUsed = 0b110,
     RxFifoEmpty = 0b111,
 }
-impl const EnumField for RxPipeNo {
-    fn into_bits(self) -> u8 {
+enum_field!(RxPipeNo, 0b111, reserved: NotUsed);
+
+/// Number of data pipes.
+pub const PIPES: usize = 6;
+
+/// Data pipe, e.g. the pipe an ACK payload is designated to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Pipe {
+    P0 = 0,
+    P1 = 1,
+    P2 = 2,
+    P3 = 3,
+    P4 = 4,
+    P5 = 5,
+}
+
+impl Pipe {
+    /// All data pipes, in order.
+    pub const ALL: [Self; PIPES] = [Self::P0, Self::P1, Self::P2, Self::P3, Self::P4, Self::P5];
+
+    /// Convert the pipe to its number.
+    pub const fn into_bits(self) -> u8 {
         self as _
     }
-    fn from_bits(bits: u8) -> Self {
-        // SAFETY: The result is guaranteed to be in the range of 0-7
-        unsafe { core::mem::transmute(bits & 0b111) }
+
+    /// Convert a pipe number to the pipe, failing on bits above 5.
+    pub const fn try_from_bits(bits: u8) -> Result<Self, InvalidBits> {
+        if let Err(e) = check_reserved(bits, 0b111) {
+            return Err(e);
+        }
+        match bits {
+            0 => Ok(Self::P0),
+            1 => Ok(Self::P1),
+            2 => Ok(Self::P2),
+            3 => Ok(Self::P3),
+            4 => Ok(Self::P4),
+            5 => Ok(Self::P5),
+            _ => Err(InvalidBits::Value {
+                field: "Pipe",
+                bits,
+            }),
+        }
+    }
+}
+
+impl TryFrom<u8> for Pipe {
+    type Error = InvalidBits;
+
+    fn try_from(bits: u8) -> Result<Self, InvalidBits> {
+        Self::try_from_bits(bits)
+    }
+}
+
+/// Interrupt flag of the [`STATUS`](crate::registers::Status) register, i.e. a cause of the IRQ.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Interrupt {
+    /// `rx_dr`: data ready in the RX FIFO.
+    RxDr = 0b0100_0000,
+    /// `tx_ds`: data sent from the TX FIFO, and acknowledged if auto-ACK is enabled.
+    TxDs = 0b0010_0000,
+    /// `max_rt`: maximum number of retransmits reached.
+    MaxRt = 0b0001_0000,
+}
+
+impl Interrupt {
+    /// Bit of the flag in the `STATUS` register.
+    pub const fn mask(self) -> u8 {
+        self as _
     }
 }
@@ -1,20 +1,79 @@
 //! <div class="warning">
-//! <strong>Requires Rust Nightly</strong>
+//! <strong>Requires Rust Nightly</strong> unless default features are disabled
 //! </div>
 //!
 #![no_std]
-#![allow(incomplete_features)]
-#![feature(generic_const_exprs)]
-#![feature(const_trait_impl)]
+#![cfg_attr(feature = "nightly", allow(incomplete_features))]
+#![cfg_attr(feature = "nightly", feature(generic_const_exprs))]
+#![cfg_attr(feature = "nightly", feature(const_trait_impl))]
 #![doc = include_str!("../README.md")]
 
+/// Implement a trait as `impl const` with the `nightly` feature, or as a plain impl on stable.
+///
+/// `impl const` is rejected by the parser on stable even in code that is configured out,
+/// so the keyword is only emitted from here.
+#[cfg(feature = "nightly")]
+macro_rules! const_impl {
+    (impl<const $n:ident: usize> $trait:ident<$arg:ident> for $ty:ty { $($body:tt)* }) => {
+        impl<const $n: usize> const $trait<$arg> for $ty { $($body)* }
+    };
+    (impl<const $n:ident: usize> $trait:ident for $ty:ty { $($body:tt)* }) => {
+        impl<const $n: usize> const $trait for $ty { $($body)* }
+    };
+    (impl<$lt:lifetime> $trait:ident for $ty:ty { $($body:tt)* }) => {
+        impl<$lt> const $trait for $ty { $($body)* }
+    };
+    (impl<$g:ident> $trait:ident for $ty:ty { $($body:tt)* }) => {
+        impl<$g> const $trait for $ty { $($body)* }
+    };
+    (impl $trait:ident for $ty:ty { $($body:tt)* }) => {
+        impl const $trait for $ty { $($body)* }
+    };
+}
+
+#[cfg(not(feature = "nightly"))]
+macro_rules! const_impl {
+    (impl<const $n:ident: usize> $trait:ident<$arg:ident> for $ty:ty { $($body:tt)* }) => {
+        impl<const $n: usize> $trait<$arg> for $ty { $($body)* }
+    };
+    (impl<const $n:ident: usize> $trait:ident for $ty:ty { $($body:tt)* }) => {
+        impl<const $n: usize> $trait for $ty { $($body)* }
+    };
+    (impl<$lt:lifetime> $trait:ident for $ty:ty { $($body:tt)* }) => {
+        impl<$lt> $trait for $ty { $($body)* }
+    };
+    (impl<$g:ident> $trait:ident for $ty:ty { $($body:tt)* }) => {
+        impl<$g> $trait for $ty { $($body)* }
+    };
+    (impl $trait:ident for $ty:ty { $($body:tt)* }) => {
+        impl $trait for $ty { $($body)* }
+    };
+}
+
+pub mod airtime;
+pub mod channel;
 pub mod commands;
+pub mod config;
+pub mod crc;
+#[cfg(feature = "driver")]
+pub mod driver;
+pub mod esb;
 pub mod fields;
+pub mod machine;
+pub mod power;
 pub mod registers;
+#[cfg(feature = "sim")]
+pub mod sim;
+#[cfg(feature = "embedded-hal")]
+pub mod spi;
+#[cfg(feature = "embedded-hal-async")]
+pub mod spi_async;
+pub mod timing;
+pub mod trace;
 
 #[cfg(test)]
 mod tests {
-    use super::{commands, fields, registers, registers::AddressRegister};
+    use super::{commands, fields, registers};
 
     #[test]
     fn test_reg_config() {
@@ -35,6 +94,11 @@ mod tests {
         // Check write command
         let write_reg = commands::WRegister(reg).bytes();
         assert_eq!(write_reg, [0b0010_0000 | 0x00, 0b0111_0100]);
+        // Check read response
+        let response = commands::RRegister::<registers::Config>::parse(&[0b0000_1110, 0b0111_0100]);
+        assert_eq!(response.status.into_bits(), 0b0000_1110);
+        assert_eq!(response.register.crco(), fields::Crco::TwoByte);
+        assert!(response.register.mask_rx_dr());
     }
 
     #[test]
@@ -71,9 +135,12 @@ mod tests {
         // Check read command
         let read_reg = commands::RRegister::<registers::Status>::bytes();
         assert_eq!(read_reg, [0 | 0x07, 0]);
-        // Check write command
+        // Check write command, without the read-only fields
         let write_reg = commands::WRegister(reg).bytes();
-        assert_eq!(write_reg, [0b0010_0000 | 0x07, 0b0011_0110]);
+        assert_eq!(write_reg, [0b0010_0000 | 0x07, 0b0011_0000]);
+        let mut buf = [0; 2];
+        assert!(commands::WRegister(reg).encode_into(&mut buf).is_ok());
+        assert_eq!(buf, write_reg);
     }
 
     #[test]
@@ -90,6 +157,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "nightly")]
     fn test_reg_rx_addr_p0() {
         // Check default
         let reg = registers::RxAddrP0::<3>::new();
@@ -106,6 +174,21 @@ mod tests {
     }
 
     #[test]
+    fn test_reg_rx_addr_p0_spi_bytes() {
+        let reg = registers::RxAddrP0::<4>::new().with_rx_addr_p0(0x17E73A6C58);
+        // Check read command
+        let read_reg = commands::RRegister::<registers::RxAddrP0<5>>::spi_bytes();
+        assert_eq!(read_reg.as_slice(), [0x0A, 0, 0, 0, 0, 0]);
+        // Check write command
+        let write_reg = commands::WRegister(reg).spi_bytes();
+        assert_eq!(
+            write_reg.as_slice(),
+            [0b0010_0000 | 0x0A, 0x58, 0x6C, 0x3A, 0xE7]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "nightly")]
     fn test_reg_tx_addr() {
         // Check default
         let reg = registers::TxAddr::<5>::new();
@@ -124,6 +207,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_reg_tx_addr_spi_bytes() {
+        let reg = registers::TxAddr::<5>::new().with_tx_addr(0xA2891FFF6A);
+        // Check read command
+        let read_reg = commands::RRegister::<registers::TxAddr<4>>::spi_bytes();
+        assert_eq!(read_reg.as_slice(), [0x10, 0, 0, 0, 0]);
+        // Check write command
+        let write_reg = commands::WRegister(reg).spi_bytes();
+        assert_eq!(
+            write_reg.as_slice(),
+            [0b0010_0000 | 0x10, 0x6A, 0xFF, 0x1F, 0x89, 0xA2]
+        );
+        // Check read response
+        let response = commands::RRegister::<registers::TxAddr<5>>::parse_slice(&[
+            0b0000_1110,
+            0x6A,
+            0xFF,
+            0x1F,
+            0x89,
+            0xA2,
+        ]);
+        assert_eq!(response.register.tx_addr(), 0xA2891FFF6A);
+    }
+
     #[test]
     fn test_reg_rx_pw_p0() {
         // Check default
@@ -157,6 +264,119 @@ mod tests {
         assert_eq!(read_reg, [0 | 0x17, 0]);
     }
 
+    #[test]
+    fn test_try_from_bits() {
+        assert_eq!(fields::Crco::try_from(1), Ok(fields::Crco::TwoByte));
+        assert_eq!(
+            fields::Crco::try_from(2),
+            Err(fields::InvalidBits::Reserved { bits: 2 })
+        );
+        assert_eq!(
+            fields::RxPipeNo::try_from(0b111),
+            Ok(fields::RxPipeNo::RxFifoEmpty)
+        );
+        assert_eq!(
+            fields::RxPipeNo::try_from(0b110),
+            Err(fields::InvalidBits::Value {
+                field: "RxPipeNo",
+                bits: 0b110
+            })
+        );
+        assert!(fields::AddressWidth::try_from(0).is_err());
+        assert!(fields::AutoRetransmitDelay::try_from(0b1111).is_ok());
+        assert_eq!(fields::Pipe::try_from(5), Ok(fields::Pipe::P5));
+        assert_eq!(
+            fields::Pipe::try_from(6),
+            Err(fields::InvalidBits::Value {
+                field: "Pipe",
+                bits: 6
+            })
+        );
+        assert_eq!(
+            fields::Pipe::try_from(8),
+            Err(fields::InvalidBits::Reserved { bits: 8 })
+        );
+
+        assert!(registers::Status::try_from_bits(0b0000_1110).is_ok());
+        assert!(registers::Status::try_from_bits(0b1000_1110).is_err());
+        assert!(registers::SetupAw::try_from_bits(0b11).is_ok());
+        assert_eq!(
+            registers::SetupAw::try_from_bits(0b0100_0011).unwrap_err(),
+            fields::InvalidBits::Reserved { bits: 0b0100_0000 }
+        );
+        assert!(registers::SetupAw::try_from_bits(0).is_err());
+        assert!(registers::FifoStatus::try_from_bits(0b0001_0001).is_ok());
+        assert!(registers::FifoStatus::try_from_bits(0b0001_0101).is_err());
+        assert!(registers::Config::try_from_bits(0b0111_1111).is_ok());
+        assert_eq!(
+            registers::Config::try_from_bits(0b1000_1000).unwrap_err(),
+            fields::InvalidBits::Reserved { bits: 0b1000_0000 }
+        );
+        assert!(registers::EnAa::try_from_bits(0b0011_1111).is_ok());
+        assert_eq!(
+            registers::EnAa::try_from_bits(0b0111_1111).unwrap_err(),
+            fields::InvalidBits::Reserved { bits: 0b0100_0000 }
+        );
+        assert!(registers::EnRxaddr::try_from_bits(0b0000_0011).is_ok());
+        assert!(registers::EnRxaddr::try_from_bits(0b1000_0011).is_err());
+        assert!(registers::RfCh::try_from_bits(125).is_ok());
+        assert!(registers::RfCh::try_from_bits(0b1000_0010).is_err());
+        assert!(registers::RfSetup::try_from_bits(0b1011_1110).is_err());
+        assert!(registers::RfSetup::try_from_bits(0b1001_0110).is_ok());
+        assert_eq!(
+            registers::RfSetup::try_from_bits(0b0100_1110).unwrap_err(),
+            fields::InvalidBits::Reserved { bits: 0b0100_0000 }
+        );
+        assert_eq!(
+            registers::RfSetup::try_from_bits(0b0000_1111).unwrap_err(),
+            fields::InvalidBits::Reserved { bits: 0b0000_0001 }
+        );
+        assert_eq!(
+            registers::RfSetup::try_from_bits(0b0010_1110).unwrap_err(),
+            fields::InvalidBits::Value {
+                field: "DataRate",
+                bits: 0b11
+            }
+        );
+        assert!(registers::Rpd::try_from_bits(1).is_ok());
+        assert!(registers::Rpd::try_from_bits(0b0000_0011).is_err());
+        assert!(registers::RxPwP0::try_from_bits(32).is_ok());
+        assert!(registers::RxPwP0::try_from_bits(0b0100_0000).is_err());
+        assert!(registers::RxPwP1::try_from_bits(0b1000_0001).is_err());
+        assert!(registers::RxPwP2::try_from_bits(0b1000_0001).is_err());
+        assert!(registers::RxPwP3::try_from_bits(0b1000_0001).is_err());
+        assert!(registers::RxPwP4::try_from_bits(0b1000_0001).is_err());
+        assert!(registers::RxPwP5::try_from_bits(0b1000_0001).is_err());
+        assert!(registers::Dynpd::try_from_bits(0b0011_1111).is_ok());
+        assert!(registers::Dynpd::try_from_bits(0b0100_0000).is_err());
+        assert!(registers::Feature::try_from_bits(0b0000_0111).is_ok());
+        assert_eq!(
+            registers::Feature::try_from_bits(0b0000_1111).unwrap_err(),
+            fields::InvalidBits::Reserved { bits: 0b0000_1000 }
+        );
+    }
+
+    #[test]
+    fn test_reg_rf_setup_data_rate() {
+        let reg = registers::RfSetup::new();
+        assert_eq!(reg.data_rate(), Ok(fields::DataRate::Mbps2));
+        let mut reg = reg.with_data_rate(fields::DataRate::Mbps1);
+        assert_eq!(reg.into_bits(), 0b0000_0110);
+        assert_eq!(reg.data_rate(), Ok(fields::DataRate::Mbps1));
+        reg.set_data_rate(fields::DataRate::Kbps250);
+        assert_eq!(reg.into_bits(), 0b0010_0110);
+        reg.set_data_rate(fields::DataRate::Mbps2);
+        assert_eq!(reg.into_bits(), 0b0000_1110);
+        // Reserved combination
+        assert_eq!(
+            reg.with_rf_dr_low(true).data_rate(),
+            Err(fields::InvalidBits::Value {
+                field: "DataRate",
+                bits: 0b11
+            })
+        );
+    }
+
     #[test]
     fn test_reg_feature() {
         // Check default
@@ -177,6 +397,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "nightly")]
     fn test_read_rx_payload() {
         let bytes = commands::RRxPayload::<32>::bytes();
         let mut expected_bytes = [0; 33];
@@ -185,8 +406,44 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "nightly")]
+    fn test_read_rx_payload_response() {
+        let mut miso = [0xA5; 33];
+        miso[0] = 0b0100_0000;
+        let response = commands::RRxPayload::<32>::parse(&miso);
+        assert!(response.status.rx_dr());
+        assert_eq!(response.status.rx_p_no(), fields::RxPipeNo::Pipe0);
+        assert_eq!(response.payload, [0xA5; 32]);
+    }
+
+    #[test]
+    fn test_read_rx_payload_spi_bytes() {
+        let bytes = commands::RRxPayload::<32>::spi_bytes();
+        let mut expected_bytes = [0; 33];
+        expected_bytes[0] = 0b0110_0001;
+        assert_eq!(bytes.as_slice(), expected_bytes);
+
+        let mut miso = [0xA5; 33];
+        miso[0] = 0b0100_0000;
+        let response = commands::RRxPayload::<32>::parse_slice(&miso);
+        assert!(response.status.rx_dr());
+        assert_eq!(response.status.rx_p_no(), fields::RxPipeNo::Pipe0);
+        assert_eq!(response.payload, [0xA5; 32]);
+    }
+
+    #[test]
+    #[cfg(feature = "nightly")]
     fn test_cmd_write_tx_payload_no_ack() {
         let bytes = commands::WTxPayloadNoack([b'H', b'e', b'l', b'l', b'o']).bytes();
         assert_eq!(bytes, [0b1011_0000, b'H', b'e', b'l', b'l', b'o']);
     }
+
+    #[test]
+    fn test_cmd_write_tx_payload_no_ack_spi_bytes() {
+        let bytes = commands::WTxPayloadNoack(*b"Hello").spi_bytes();
+        assert_eq!(
+            bytes.as_slice(),
+            [0b1011_0000, b'H', b'e', b'l', b'l', b'o']
+        );
+    }
 }
@@ -0,0 +1,685 @@
+//! Sans-IO state machine for transmitting and receiving with the nRF24L01+.
+//!
+//! [`Radio`] performs no I/O. It is fed [`Input`]s (IRQ, a finished SPI transfer, an elapsed
+//! timer, a request to send or receive) and answers with [`Action`]s (an SPI transfer built from
+//! [`commands`], CE high or low, start a timer) and [`Event`]s for the application. The caller
+//! performs the actions in order with whatever HAL or executor it uses and feeds the MISO bytes of
+//! every SPI transfer back with [`Input::SpiDone`].
+//!
+//! At most one SPI transfer is requested per input, as the last action, and no other input
+//! is expected until it is done. IRQs that arrive in the meantime are kept and handled once the
+//! radio is waiting again.
+//!
+//! The chip must be configured and powered up, in Standby-I, with dynamic payload length
+//! enabled on the receiving pipes:
+//! - Sending writes the TX payload and pulses CE for [`TSTBY2A_US`](timing::TSTBY2A_US). The
+//!   chip handles auto-retransmit and the radio waits for `TX_DS` or `MAX_RT`. On `MAX_RT` the
+//!   TX FIFO is flushed so the next payload can be sent.
+//! - Receiving sets CE high and, on every `RX_DR`, drains the RX FIFO with
+//!   [`RRxPlWid`](commands::RRxPlWid) and [`RRxPayload`](commands::RRxPayload). Payloads with
+//!   an invalid width are flushed. Sending while receiving drains the RX FIFO first.
+//!
+//! ## Example
+//! ```rust
+//! use nrf24l01_commands::machine::{Action, Input, Radio};
+//! use nrf24l01_commands::registers;
+//!
+//! let mut radio = Radio::new(registers::Config::new().with_pwr_up(true));
+//!
+//! // Write the TX payload
+//! let mut actions = radio.handle(Input::Send(&[1, 2, 3])).unwrap();
+//! let Some(Action::Spi(bytes)) = actions.next() else { panic!() };
+//! assert_eq!(bytes.as_slice(), &[0b1010_0000, 1, 2, 3]);
+//!
+//! // Pulse CE
+//! let mut actions = radio.handle(Input::SpiDone(&[0b0000_1110; 4])).unwrap();
+//! assert_eq!(actions.next(), Some(Action::CeHigh));
+//! assert_eq!(actions.next(), Some(Action::StartTimer { us: 130 }));
+//! let mut actions = radio.handle(Input::TimerElapsed).unwrap();
+//! assert_eq!(actions.next(), Some(Action::CeLow));
+//! ```
+use crate::commands::{self, SpiBytes};
+use crate::fields::{self, Pipe, RxPipeNo};
+use crate::{registers, timing};
+
+/// Maximum number of actions answering one input.
+const MAX_ACTIONS: usize = 3;
+
+/// Input to the state machine.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Input<'a> {
+    /// The IRQ pin was asserted.
+    Irq,
+    /// The requested SPI transfer is done, with the bytes shifted out on MISO.
+    SpiDone(&'a [u8]),
+    /// The requested timer elapsed.
+    TimerElapsed,
+    /// Send a payload of 1 to 32 bytes.
+    Send(&'a [u8]),
+    /// Start receiving.
+    Receive,
+}
+
+/// Action to perform.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Action {
+    /// Perform an SPI transfer with these MOSI bytes and answer with [`Input::SpiDone`].
+    Spi(SpiBytes),
+    /// Set CE high.
+    CeHigh,
+    /// Set CE low.
+    CeLow,
+    /// Start a timer and answer with [`Input::TimerElapsed`].
+    StartTimer {
+        /// Duration in microseconds.
+        us: u32,
+    },
+    /// Notify the application.
+    Event(Event),
+}
+
+/// Outcome reported to the application.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Event {
+    /// The payload was sent, and acknowledged if auto-ACK is enabled.
+    Sent {
+        /// Number of retransmits, from [`OBSERVE_TX`](registers::ObserveTx).
+        retransmits: u8,
+    },
+    /// The payload was not acknowledged after the maximum number of retransmits.
+    /// The TX FIFO was flushed.
+    MaxRetransmits,
+    /// A payload was received.
+    Received {
+        /// Data pipe the payload was received on.
+        pipe: Pipe,
+        /// Payload.
+        payload: Payload,
+    },
+}
+
+/// A received payload of 1 to 32 bytes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Payload {
+    len: u8,
+    data: [u8; commands::MAX_PAYLOAD_WIDTH],
+}
+
+impl Payload {
+    /// Payload bytes.
+    pub fn as_slice(&self) -> &[u8] {
+        &self.data[..self.len as usize]
+    }
+
+    /// Payload width in bytes.
+    pub const fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    /// Whether the payload is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+/// State machine error. The state is unchanged.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The input is not expected in the current state, e.g. sending while a payload is in
+    /// flight or an SPI transfer that was not requested.
+    UnexpectedInput,
+    /// The payload to send or the MISO bytes of a transfer are invalid.
+    Command(commands::Error),
+}
+
+/// Actions answering an input, in the order they must be performed.
+#[derive(Copy, Clone, Debug)]
+pub struct Actions {
+    actions: [Option<Action>; MAX_ACTIONS],
+    next: usize,
+}
+
+impl Actions {
+    const fn new() -> Self {
+        Self {
+            actions: [None; MAX_ACTIONS],
+            next: 0,
+        }
+    }
+
+    fn push(&mut self, action: Action) {
+        let slot = self.actions.iter_mut().find(|slot| slot.is_none());
+        *slot.expect("too many actions") = Some(action);
+    }
+}
+
+impl Iterator for Actions {
+    type Item = Action;
+
+    fn next(&mut self) -> Option<Action> {
+        let action = self.actions.get_mut(self.next)?.take();
+        self.next += 1;
+        action
+    }
+}
+
+/// Radio state.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum State {
+    /// Standby-I with nothing in progress.
+    Idle,
+    /// Writing `CONFIG` with `prim_rx` cleared, followed by the TX payload.
+    TxConfig(SpiBytes),
+    /// Writing the TX payload.
+    TxPayload,
+    /// CE is high for the TX settling time.
+    TxPulse,
+    /// Waiting for `TX_DS` or `MAX_RT`.
+    TxWait,
+    /// Clearing `TX_DS` and `MAX_RT`.
+    TxClear,
+    /// Reading `OBSERVE_TX` after `TX_DS`.
+    TxObserve,
+    /// Flushing the TX FIFO after `MAX_RT`.
+    TxFlush,
+    /// Writing `CONFIG` with `prim_rx` set.
+    RxConfig,
+    /// CE is high for the RX settling time.
+    RxSettle,
+    /// Waiting for `RX_DR`.
+    RxListen,
+    /// Clearing `RX_DR`.
+    RxClear,
+    /// Reading the width of the top payload of the RX FIFO.
+    RxWidth,
+    /// Reading the top payload of the RX FIFO, of the given width.
+    RxPayload(u8),
+    /// Flushing the RX FIFO after an invalid payload width.
+    RxFlush,
+}
+
+/// Sans-IO radio.
+#[derive(Copy, Clone, Debug)]
+pub struct Radio {
+    state: State,
+    config: registers::Config,
+    irq_pending: bool,
+    /// TX payload to send once the RX FIFO is drained.
+    send_pending: Option<SpiBytes>,
+}
+
+impl Radio {
+    /// Create the radio for a chip in Standby-I with the [`CONFIG`](registers::Config) register
+    /// set to `config`.
+    pub const fn new(config: registers::Config) -> Self {
+        Self {
+            state: State::Idle,
+            config,
+            irq_pending: false,
+            send_pending: None,
+        }
+    }
+
+    /// Whether nothing is in progress and the radio is in Standby-I.
+    pub fn is_idle(&self) -> bool {
+        self.state == State::Idle
+    }
+
+    /// Whether the radio is receiving.
+    pub fn is_receiving(&self) -> bool {
+        matches!(
+            self.state,
+            State::RxSettle
+                | State::RxListen
+                | State::RxClear
+                | State::RxWidth
+                | State::RxPayload(_)
+                | State::RxFlush
+        )
+    }
+
+    /// Handle an input and return the actions to perform.
+    ///
+    /// Sending is possible while idle or listening, which stops receiving. The RX FIFO is
+    /// drained first, so payloads received before are still reported. Sending during the RX
+    /// settling time is rejected, as its timer is still running.
+    /// Receiving is possible while idle.
+    pub fn handle(&mut self, input: Input<'_>) -> Result<Actions, Error> {
+        let mut actions = Actions::new();
+        match (self.state, input) {
+            (State::Idle | State::RxListen, Input::Send(payload)) => {
+                let payload = commands::WTxPayloadSlice::new(payload)
+                    .map_err(Error::Command)?
+                    .spi_bytes();
+                if self.state == State::Idle {
+                    self.send(&mut actions, payload);
+                } else {
+                    // RX_DR may be set without an IRQ handled yet
+                    actions.push(Action::CeLow);
+                    self.send_pending = Some(payload);
+                    self.state = State::RxListen;
+                    self.clear_irq(&mut actions);
+                }
+            }
+            (State::Idle, Input::Receive) => {
+                if self.config.prim_rx() {
+                    self.settle(&mut actions, State::RxSettle);
+                } else {
+                    self.config.set_prim_rx(true);
+                    let bytes = commands::WRegister(self.config).bytes();
+                    self.spi(&mut actions, State::RxConfig, &bytes);
+                }
+            }
+            (State::Idle, Input::Irq) => {}
+            (State::TxWait | State::RxListen, Input::Irq) => self.clear_irq(&mut actions),
+            (_, Input::Irq) => self.irq_pending = true,
+            (State::TxPulse, Input::TimerElapsed) => {
+                actions.push(Action::CeLow);
+                self.wait(&mut actions, State::TxWait);
+            }
+            (State::RxSettle, Input::TimerElapsed) => self.wait(&mut actions, State::RxListen),
+            (state, Input::SpiDone(miso)) => self.spi_done(&mut actions, state, miso)?,
+            _ => return Err(Error::UnexpectedInput),
+        }
+        Ok(actions)
+    }
+
+    /// Handle the MISO bytes of the transfer requested in `state`.
+    fn spi_done(&mut self, actions: &mut Actions, state: State, miso: &[u8]) -> Result<(), Error> {
+        match state {
+            State::TxConfig(payload) => {
+                commands::WRegister::<registers::Config>::decode(miso).map_err(Error::Command)?;
+                self.spi(actions, State::TxPayload, payload.as_slice());
+            }
+            State::TxPayload => {
+                commands::WTxPayload::<1>::decode(miso).map_err(Error::Command)?;
+                self.settle(actions, State::TxPulse);
+            }
+            State::TxClear => {
+                let status = commands::ClearInterrupts::decode(miso).map_err(Error::Command)?;
+                if status.max_rt() {
+                    self.spi(actions, State::TxFlush, &commands::FlushTx::bytes());
+                } else if status.tx_ds() {
+                    let bytes = commands::RRegister::<registers::ObserveTx>::bytes();
+                    self.spi(actions, State::TxObserve, &bytes);
+                } else {
+                    self.wait(actions, State::TxWait);
+                }
+            }
+            State::TxObserve => {
+                let observe_tx = commands::RRegister::<registers::ObserveTx>::decode(miso)
+                    .map_err(Error::Command)?
+                    .register;
+                let retransmits = observe_tx.arc_cnt();
+                actions.push(Action::Event(Event::Sent { retransmits }));
+                self.state = State::Idle;
+            }
+            State::TxFlush => {
+                commands::FlushTx::decode(miso).map_err(Error::Command)?;
+                actions.push(Action::Event(Event::MaxRetransmits));
+                self.state = State::Idle;
+            }
+            State::RxConfig => {
+                commands::WRegister::<registers::Config>::decode(miso).map_err(Error::Command)?;
+                self.settle(actions, State::RxSettle);
+            }
+            State::RxClear => {
+                let status = commands::ClearInterrupts::decode(miso).map_err(Error::Command)?;
+                if status.rx_p_no() == RxPipeNo::RxFifoEmpty {
+                    self.listen(actions);
+                } else {
+                    self.spi(actions, State::RxWidth, &commands::RRxPlWid::bytes());
+                }
+            }
+            State::RxWidth => {
+                let response = commands::RRxPlWid::decode(miso).map_err(Error::Command)?;
+                let width = response.width as usize;
+                if response.status.rx_p_no() == RxPipeNo::RxFifoEmpty {
+                    self.listen(actions);
+                } else if (1..=commands::MAX_PAYLOAD_WIDTH).contains(&width) {
+                    let mut payload = [0; commands::MAX_PAYLOAD_WIDTH];
+                    let read = commands::RRxPayloadSlice::new(&mut payload[..width])
+                        .map_err(Error::Command)?;
+                    let bytes = read.spi_bytes();
+                    self.spi(actions, State::RxPayload(width as u8), bytes.as_slice());
+                } else {
+                    self.spi(actions, State::RxFlush, &commands::FlushRx::bytes());
+                }
+            }
+            State::RxPayload(width) => {
+                let mut data = [0; commands::MAX_PAYLOAD_WIDTH];
+                let read = commands::RRxPayloadSlice::new(&mut data[..width as usize])
+                    .map_err(Error::Command)?;
+                let status = read.decode(miso).map_err(Error::Command)?;
+                if let Ok(pipe) = Pipe::try_from_bits(status.rx_p_no().into_bits()) {
+                    let payload = Payload { len: width, data };
+                    actions.push(Action::Event(Event::Received { pipe, payload }));
+                }
+                self.spi(actions, State::RxWidth, &commands::RRxPlWid::bytes());
+            }
+            State::RxFlush => {
+                commands::FlushRx::decode(miso).map_err(Error::Command)?;
+                self.listen(actions);
+            }
+            State::Idle | State::TxPulse | State::TxWait | State::RxSettle | State::RxListen => {
+                return Err(Error::UnexpectedInput);
+            }
+        }
+        Ok(())
+    }
+
+    /// Write the TX payload, clearing `prim_rx` first if needed.
+    fn send(&mut self, actions: &mut Actions, payload: SpiBytes) {
+        if self.config.prim_rx() {
+            self.config.set_prim_rx(false);
+            let bytes = commands::WRegister(self.config).bytes();
+            self.spi(actions, State::TxConfig(payload), &bytes);
+        } else {
+            self.spi(actions, State::TxPayload, payload.as_slice());
+        }
+    }
+
+    /// Listen again once the RX FIFO is drained, or send the payload that stopped receiving.
+    fn listen(&mut self, actions: &mut Actions) {
+        match self.send_pending.take() {
+            Some(payload) => self.send(actions, payload),
+            None => self.wait(actions, State::RxListen),
+        }
+    }
+
+    /// Request an SPI transfer and enter `state` until it is done.
+    fn spi(&mut self, actions: &mut Actions, state: State, bytes: &[u8]) {
+        actions.push(Action::Spi(SpiBytes::new(bytes)));
+        self.state = state;
+    }
+
+    /// Set CE high and enter `state` for the settling time.
+    fn settle(&mut self, actions: &mut Actions, state: State) {
+        actions.push(Action::CeHigh);
+        actions.push(Action::StartTimer {
+            us: timing::TSTBY2A_US,
+        });
+        self.state = state;
+    }
+
+    /// Wait for an IRQ in `state`, handling one that arrived in the meantime.
+    fn wait(&mut self, actions: &mut Actions, state: State) {
+        self.state = state;
+        if self.irq_pending {
+            self.clear_irq(actions);
+        }
+    }
+
+    /// Clear the IRQ flags of the current mode, reading the status.
+    fn clear_irq(&mut self, actions: &mut Actions) {
+        self.irq_pending = false;
+        let (command, state) = match self.state {
+            State::RxListen => (
+                commands::ClearInterrupts::only(fields::Interrupt::RxDr),
+                State::RxClear,
+            ),
+            _ => (
+                commands::ClearInterrupts::only(fields::Interrupt::TxDs)
+                    .with(fields::Interrupt::MaxRt),
+                State::TxClear,
+            ),
+        };
+        self.spi(actions, state, &command.bytes());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STATUS: u8 = 0b0000_1110;
+
+    /// Handle an input and collect the actions.
+    fn step(radio: &mut Radio, input: Input<'_>) -> ([Option<Action>; MAX_ACTIONS], usize) {
+        let mut collected = [None; MAX_ACTIONS];
+        let mut len = 0;
+        for action in radio.handle(input).unwrap() {
+            collected[len] = Some(action);
+            len += 1;
+        }
+        (collected, len)
+    }
+
+    /// Handle an input and return the MOSI bytes of the single action, an SPI transfer.
+    fn expect_spi(radio: &mut Radio, input: Input<'_>) -> SpiBytes {
+        match step(radio, input) {
+            ([Some(Action::Spi(bytes)), None, None], 1) => bytes,
+            actions => panic!("expected an SPI transfer, got {actions:?}"),
+        }
+    }
+
+    #[test]
+    fn test_tx() {
+        let config = registers::Config::new()
+            .with_pwr_up(true)
+            .with_prim_rx(true);
+        let mut radio = Radio::new(config);
+        assert_eq!(
+            radio.handle(Input::Send(&[])).unwrap_err(),
+            Error::Command(commands::Error::InvalidPayloadWidth { width: 0 })
+        );
+
+        // prim_rx is cleared before writing the payload
+        let bytes = expect_spi(&mut radio, Input::Send(&[1, 2]));
+        assert_eq!(bytes.as_slice(), &[0b0010_0000, 0b0000_1010]);
+        assert_eq!(
+            radio.handle(Input::Send(&[3])).unwrap_err(),
+            Error::UnexpectedInput
+        );
+        let bytes = expect_spi(&mut radio, Input::SpiDone(&[STATUS, 0]));
+        assert_eq!(bytes.as_slice(), &[0b1010_0000, 1, 2]);
+        let (actions, _) = step(&mut radio, Input::SpiDone(&[STATUS; 3]));
+        assert_eq!(
+            actions,
+            [
+                Some(Action::CeHigh),
+                Some(Action::StartTimer { us: 130 }),
+                None
+            ]
+        );
+        let (actions, _) = step(&mut radio, Input::TimerElapsed);
+        assert_eq!(actions, [Some(Action::CeLow), None, None]);
+
+        // TX_DS, then 2 retransmits in OBSERVE_TX
+        let bytes = expect_spi(&mut radio, Input::Irq);
+        assert_eq!(bytes.as_slice(), &[0b0010_0111, 0b0011_0000]);
+        let bytes = expect_spi(&mut radio, Input::SpiDone(&[0b0010_1110, 0]));
+        assert_eq!(bytes.as_slice(), &[0x08, 0]);
+        let (actions, _) = step(&mut radio, Input::SpiDone(&[STATUS, 0x12]));
+        assert_eq!(
+            actions,
+            [
+                Some(Action::Event(Event::Sent { retransmits: 2 })),
+                None,
+                None
+            ]
+        );
+        assert!(radio.is_idle());
+
+        // MAX_RT while CE is still high flushes the TX FIFO
+        expect_spi(&mut radio, Input::Send(&[1]));
+        step(&mut radio, Input::SpiDone(&[STATUS; 2]));
+        let (actions, len) = step(&mut radio, Input::Irq);
+        assert_eq!(len, 0, "{actions:?}");
+        let (actions, _) = step(&mut radio, Input::TimerElapsed);
+        assert_eq!(actions[0], Some(Action::CeLow));
+        assert_eq!(
+            actions[1],
+            Some(Action::Spi(SpiBytes::new(&[0b0010_0111, 0b0011_0000])))
+        );
+        let bytes = expect_spi(&mut radio, Input::SpiDone(&[0b0001_1110, 0]));
+        assert_eq!(bytes.as_slice(), &[0b1110_0001]);
+        let (actions, _) = step(&mut radio, Input::SpiDone(&[STATUS]));
+        assert_eq!(actions[0], Some(Action::Event(Event::MaxRetransmits)));
+        assert!(radio.is_idle());
+    }
+
+    #[test]
+    fn test_rx() {
+        let mut radio = Radio::new(registers::Config::new().with_pwr_up(true));
+        assert_eq!(
+            radio.handle(Input::SpiDone(&[STATUS])).unwrap_err(),
+            Error::UnexpectedInput
+        );
+
+        let bytes = expect_spi(&mut radio, Input::Receive);
+        assert_eq!(bytes.as_slice(), &[0b0010_0000, 0b0000_1011]);
+        step(&mut radio, Input::SpiDone(&[STATUS, 0]));
+        assert!(radio.is_receiving());
+        // The RX settling timer is still running
+        assert_eq!(
+            radio.handle(Input::Send(&[1])).unwrap_err(),
+            Error::UnexpectedInput
+        );
+        let (_, len) = step(&mut radio, Input::TimerElapsed);
+        assert_eq!(len, 0);
+
+        // Two payloads on pipes 1 and 2, then an invalid width
+        let bytes = expect_spi(&mut radio, Input::Irq);
+        assert_eq!(bytes.as_slice(), &[0b0010_0111, 0b0100_0000]);
+        let bytes = expect_spi(&mut radio, Input::SpiDone(&[0b0100_0010, 0]));
+        assert_eq!(bytes.as_slice(), &[0b0110_0000, 0]);
+        let bytes = expect_spi(&mut radio, Input::SpiDone(&[0b0000_0010, 2]));
+        assert_eq!(bytes.as_slice(), &[0b0110_0001, 0, 0]);
+        let (actions, _) = step(&mut radio, Input::SpiDone(&[0b0000_0010, 7, 8]));
+        let Some(Action::Event(Event::Received { pipe, payload })) = actions[0] else {
+            panic!("{actions:?}");
+        };
+        assert_eq!((pipe, payload.as_slice()), (Pipe::P1, &[7, 8][..]));
+        assert_eq!(
+            actions[1],
+            Some(Action::Spi(SpiBytes::new(&[0b0110_0000, 0])))
+        );
+
+        // IRQ while draining is handled once listening
+        step(&mut radio, Input::Irq);
+        let bytes = expect_spi(&mut radio, Input::SpiDone(&[0b0000_0100, 1]));
+        assert_eq!(bytes.as_slice(), &[0b0110_0001, 0]);
+        let (actions, _) = step(&mut radio, Input::SpiDone(&[0b0000_0100, 9]));
+        assert!(matches!(
+            actions[0],
+            Some(Action::Event(Event::Received { pipe: Pipe::P2, .. }))
+        ));
+        let bytes = expect_spi(&mut radio, Input::SpiDone(&[0b0000_0100, 33]));
+        assert_eq!(bytes.as_slice(), &[0b1110_0010]);
+        let bytes = expect_spi(&mut radio, Input::SpiDone(&[0b0000_0100]));
+        assert_eq!(bytes.as_slice(), &[0b0010_0111, 0b0100_0000]);
+        let (_, len) = step(&mut radio, Input::SpiDone(&[STATUS, 0]));
+        assert_eq!(len, 0);
+
+        // Sending stops receiving, after draining a payload received meanwhile
+        let (actions, _) = step(&mut radio, Input::Send(&[1]));
+        assert_eq!(actions[0], Some(Action::CeLow));
+        assert_eq!(
+            actions[1],
+            Some(Action::Spi(SpiBytes::new(&[0b0010_0111, 0b0100_0000])))
+        );
+        assert_eq!(
+            radio.handle(Input::Send(&[1])).unwrap_err(),
+            Error::UnexpectedInput
+        );
+        let bytes = expect_spi(&mut radio, Input::SpiDone(&[0b0100_0000, 0]));
+        assert_eq!(bytes.as_slice(), &[0b0110_0000, 0]);
+        let bytes = expect_spi(&mut radio, Input::SpiDone(&[0b0000_0000, 1]));
+        assert_eq!(bytes.as_slice(), &[0b0110_0001, 0]);
+        let (actions, _) = step(&mut radio, Input::SpiDone(&[0b0000_0000, 5]));
+        assert!(matches!(
+            actions[0],
+            Some(Action::Event(Event::Received { pipe: Pipe::P0, .. }))
+        ));
+        assert_eq!(
+            actions[1],
+            Some(Action::Spi(SpiBytes::new(&[0b0110_0000, 0])))
+        );
+        let bytes = expect_spi(&mut radio, Input::SpiDone(&[STATUS, 0]));
+        assert_eq!(bytes.as_slice(), &[0b0010_0000, 0b0000_1010]);
+        let bytes = expect_spi(&mut radio, Input::SpiDone(&[STATUS, 0]));
+        assert_eq!(bytes.as_slice(), &[0b1010_0000, 1]);
+    }
+
+    #[test]
+    #[cfg(feature = "sim")]
+    fn test_sim() {
+        extern crate std;
+        use crate::sim;
+        use crate::spi::SpiDeviceExt;
+        use embedded_hal::digital::OutputPin;
+        use embedded_hal::spi::SpiDevice;
+        use std::borrow::ToOwned;
+
+        let ptx = sim::Nrf24l01::new();
+        let prx = sim::Nrf24l01::new();
+        let medium = sim::Medium::new([&ptx, &prx]);
+        let config = registers::Config::new().with_pwr_up(true);
+        for chip in [&ptx, &prx] {
+            let mut spi = chip.spi();
+            spi.execute(commands::WRegister(config)).unwrap();
+            spi.execute(commands::WRegister(
+                registers::Feature::new().with_en_dpl(true),
+            ))
+            .unwrap();
+            spi.execute(commands::WRegister(registers::Dynpd::from_bits(1)))
+                .unwrap();
+        }
+
+        /// Perform actions on a chip until the radio waits for an IRQ or a timer.
+        fn run(
+            chip: &sim::Nrf24l01,
+            radio: &mut Radio,
+            input: Input<'_>,
+            events: &mut [Option<Event>; 4],
+        ) -> bool {
+            let mut actions = radio.handle(input).unwrap();
+            while let Some(action) = actions.next() {
+                match action {
+                    Action::Spi(mut bytes) => {
+                        chip.spi().transfer_in_place(bytes.as_mut_slice()).unwrap();
+                        actions = radio.handle(Input::SpiDone(bytes.as_slice())).unwrap();
+                    }
+                    Action::CeHigh => chip.ce().set_high().unwrap(),
+                    Action::CeLow => chip.ce().set_low().unwrap(),
+                    Action::StartTimer { .. } => return true,
+                    Action::Event(event) => {
+                        *events.iter_mut().find(|e| e.is_none()).unwrap() = Some(event);
+                    }
+                }
+            }
+            false
+        }
+
+        let mut events = [None; 4];
+        let mut tx = Radio::new(config);
+        let mut rx = Radio::new(config);
+        assert!(run(&prx, &mut rx, Input::Receive, &mut events));
+        run(&prx, &mut rx, Input::TimerElapsed, &mut events);
+
+        for payload in [&[1, 2, 3][..], &[4]] {
+            assert!(run(&ptx, &mut tx, Input::Send(payload), &mut events));
+            assert_eq!(medium.step(), 1);
+            run(&ptx, &mut tx, Input::TimerElapsed, &mut events);
+            assert!(ptx.irq_asserted());
+            run(&ptx, &mut tx, Input::Irq, &mut events);
+            assert!(tx.is_idle());
+        }
+        assert!(prx.irq_asserted());
+        run(&prx, &mut rx, Input::Irq, &mut events);
+        assert!(!ptx.irq_asserted() && !prx.irq_asserted());
+        assert_eq!(prx.rx_fifo_len(), 0);
+
+        let sent = Some(Event::Sent { retransmits: 0 });
+        assert_eq!(events[..2], [sent, sent]);
+        let received = [events[2], events[3]].map(|event| match event {
+            Some(Event::Received { pipe, payload }) => (pipe, payload.as_slice().to_owned()),
+            e => panic!("{e:?}"),
+        });
+        assert_eq!(
+            received,
+            [(Pipe::P0, [1, 2, 3].into()), (Pipe::P0, [4].into())]
+        );
+    }
+}
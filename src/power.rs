@@ -0,0 +1,282 @@
+//! Current consumption and battery life estimation.
+//!
+//! Currents are typical values from the datasheet:
+//!
+//! | Mode                 | Current                                    |
+//! |----------------------|--------------------------------------------|
+//! | Power down           | 900 nA                                     |
+//! | Standby-I            | 26 µA                                      |
+//! | TX                   | 7.0 - 11.3 mA, by [`RfPower`](fields::RfPower) |
+//! | RX                   | 12.6 - 13.5 mA, by data rate               |
+//! | TX settling          | 8.0 mA                                     |
+//! | RX settling          | 8.9 mA                                     |
+//!
+//! Every transmitted packet costs the TX settling time and its time on air in TX mode. If it
+//! is acknowledged, it also costs the RX settling time and the time on air of the ACK in RX
+//! mode. The rest of the [`Traffic`] period is spent in RX mode, Standby-I and power down.
+//!
+//! ## Example
+//! ```rust
+//! use nrf24l01_commands::{fields, power, registers};
+//!
+//! // A coin cell node sending 8 bytes every second at 0dBm and 1Mbps, with an empty ACK
+//! let estimator = power::Estimator::new(
+//!     registers::SetupAw::new(),
+//!     registers::Config::new(),
+//!     registers::RfSetup::new().with_rf_dr_high(fields::RfDrHigh::Mbps1),
+//! );
+//! let traffic = power::Traffic {
+//!     period_us: 1_000_000,
+//!     packets: 1,
+//!     payload_len: 8,
+//!     ack_len: Some(0),
+//!     retransmits: 0,
+//!     rx_us: 0,
+//!     standby_us: 2_000,
+//! };
+//! let estimate = estimator.estimate(&traffic).unwrap();
+//! assert_eq!(estimate.average_na, 5_455);
+//!
+//! // A 220mAh CR2032 lasts about 4.6 years
+//! let life = estimate.battery_life(220);
+//! assert_eq!(life.as_secs() / 86_400, 1680);
+//! ```
+use crate::{airtime, esb, fields, registers, timing};
+use core::time::Duration;
+
+/// Power down current in nanoamps.
+pub const POWER_DOWN_NA: u64 = 900;
+/// Standby-I current in nanoamps.
+pub const STANDBY_I_NA: u64 = 26_000;
+/// Average current during the TX settling time (`Tstby2a`) in nanoamps.
+pub const TX_SETTLE_NA: u64 = 8_000_000;
+/// Average current during the RX settling time (`Tstby2a`) in nanoamps.
+pub const RX_SETTLE_NA: u64 = 8_900_000;
+
+/// TX mode current at an output power, in nanoamps.
+pub const fn tx_na(rf_pwr: fields::RfPower) -> u64 {
+    match rf_pwr {
+        fields::RfPower::Neg18Dbm => 7_000_000,
+        fields::RfPower::Neg12Dbm => 7_500_000,
+        fields::RfPower::Neg6Dbm => 9_000_000,
+        fields::RfPower::Dbm0 => 11_300_000,
+    }
+}
+
+/// RX mode current at the data rate of `rf_setup`, in nanoamps.
+pub const fn rx_na(rf_setup: registers::RfSetup) -> u64 {
+    match rf_setup.effective_data_rate() {
+        fields::DataRate::Kbps250 => 12_600_000,
+        fields::DataRate::Mbps1 => 13_100_000,
+        fields::DataRate::Mbps2 => 13_500_000,
+    }
+}
+
+/// Estimation error.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// Time on air cannot be computed, e.g. a payload is longer than 32 bytes.
+    Airtime(esb::Error),
+    /// Packets, RX and Standby-I do not fit in the period.
+    PeriodTooShort,
+}
+
+/// Traffic pattern, repeated every period.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Traffic {
+    /// Period in microseconds.
+    pub period_us: u64,
+    /// Number of packets transmitted per period.
+    pub packets: u32,
+    /// Payload length of transmitted packets in bytes.
+    pub payload_len: usize,
+    /// ACK payload length in bytes, or `None` if packets are not acknowledged.
+    pub ack_len: Option<usize>,
+    /// Average number of retransmits per packet.
+    pub retransmits: u32,
+    /// Time listening in RX mode per period, in microseconds.
+    pub rx_us: u64,
+    /// Time in Standby-I per period, in microseconds. The remaining time is spent in power down.
+    pub standby_us: u64,
+}
+
+/// Estimated current consumption.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Estimate {
+    /// Average current in nanoamps.
+    pub average_na: u64,
+    /// Time in TX or RX mode, including settling, per period in microseconds.
+    pub active_us: u64,
+    /// Period in microseconds.
+    pub period_us: u64,
+}
+
+impl Estimate {
+    /// Fraction of the period in TX or RX mode, in parts per million.
+    pub const fn duty_cycle_ppm(&self) -> u64 {
+        self.active_us * 1_000_000 / self.period_us
+    }
+
+    /// Projected battery life for a battery of `capacity_mah`, ignoring self-discharge.
+    pub const fn battery_life(&self, capacity_mah: u32) -> Duration {
+        // mAh * 10^6 nA/mA * 3600 s/h
+        Duration::from_secs(capacity_mah as u64 * 1_000_000 * 3600 / self.average_na)
+    }
+}
+
+/// Estimates current consumption from register values.
+#[derive(Copy, Clone, Debug)]
+pub struct Estimator {
+    setup_aw: registers::SetupAw,
+    config: registers::Config,
+    rf_setup: registers::RfSetup,
+}
+
+impl Estimator {
+    /// Create an estimator for the address width, CRC, data rate and output power of the given
+    /// registers.
+    pub const fn new(
+        setup_aw: registers::SetupAw,
+        config: registers::Config,
+        rf_setup: registers::RfSetup,
+    ) -> Self {
+        Self {
+            setup_aw,
+            config,
+            rf_setup,
+        }
+    }
+
+    /// Time on air of a packet in nanoseconds.
+    const fn time_on_air_ns(&self, payload_len: usize) -> Result<u64, Error> {
+        match airtime::time_on_air_ns(self.setup_aw, self.config, self.rf_setup, payload_len) {
+            Ok(ns) => Ok(ns as u64),
+            Err(e) => Err(Error::Airtime(e)),
+        }
+    }
+
+    /// Estimate the average current of a traffic pattern.
+    pub const fn estimate(&self, traffic: &Traffic) -> Result<Estimate, Error> {
+        let settle_us = timing::TSTBY2A_US as u64;
+        // Charges in nA * µs, and times in ns
+        let tx_ns = match self.time_on_air_ns(traffic.payload_len) {
+            Ok(ns) => ns,
+            Err(e) => return Err(e),
+        };
+        let mut attempt_charge =
+            settle_us * TX_SETTLE_NA + tx_ns * tx_na(self.rf_setup.rf_pwr()) / 1000;
+        let mut attempt_ns = settle_us * 1000 + tx_ns;
+        if let Some(ack_len) = traffic.ack_len {
+            let ack_ns = match self.time_on_air_ns(ack_len) {
+                Ok(ns) => ns,
+                Err(e) => return Err(e),
+            };
+            attempt_charge += settle_us * RX_SETTLE_NA + ack_ns * rx_na(self.rf_setup) / 1000;
+            attempt_ns += settle_us * 1000 + ack_ns;
+        }
+        let attempts = traffic.packets as u64 * (1 + traffic.retransmits as u64);
+
+        let active_us = (attempts * attempt_ns).div_ceil(1000) + traffic.rx_us;
+        let busy_us = active_us + traffic.standby_us;
+        if traffic.period_us == 0 || busy_us > traffic.period_us {
+            return Err(Error::PeriodTooShort);
+        }
+        let charge = attempts * attempt_charge
+            + traffic.rx_us * rx_na(self.rf_setup)
+            + traffic.standby_us * STANDBY_I_NA
+            + (traffic.period_us - busy_us) * POWER_DOWN_NA;
+        Ok(Estimate {
+            average_na: charge / traffic.period_us,
+            active_us,
+            period_us: traffic.period_us,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRAFFIC: Traffic = Traffic {
+        period_us: 1_000_000,
+        packets: 0,
+        payload_len: 0,
+        ack_len: None,
+        retransmits: 0,
+        rx_us: 0,
+        standby_us: 0,
+    };
+
+    #[test]
+    fn test_currents() {
+        let rf_setup = registers::RfSetup::new();
+        assert_eq!(tx_na(rf_setup.rf_pwr()), 11_300_000);
+        assert_eq!(tx_na(fields::RfPower::Neg18Dbm), 7_000_000);
+        assert_eq!(rx_na(rf_setup), 13_500_000);
+        assert_eq!(rx_na(rf_setup.with_rf_dr_low(true)), 12_600_000);
+    }
+
+    #[test]
+    fn test_estimate() {
+        let estimator = Estimator::new(
+            registers::SetupAw::new(),
+            registers::Config::new(),
+            registers::RfSetup::new(),
+        );
+
+        // Power down and Standby-I only
+        let estimate = estimator.estimate(&TRAFFIC).unwrap();
+        assert_eq!(estimate.average_na, 900);
+        assert_eq!(estimate.duty_cycle_ppm(), 0);
+        let traffic = Traffic {
+            standby_us: 1_000_000,
+            ..TRAFFIC
+        };
+        assert_eq!(estimator.estimate(&traffic).unwrap().average_na, 26_000);
+
+        // Always listening
+        let traffic = Traffic {
+            rx_us: 1_000_000,
+            ..TRAFFIC
+        };
+        let estimate = estimator.estimate(&traffic).unwrap();
+        assert_eq!(estimate.average_na, 13_500_000);
+        assert_eq!(estimate.duty_cycle_ppm(), 1_000_000);
+        assert_eq!(estimate.battery_life(1350).as_secs(), 360_000);
+
+        // 10 unacknowledged packets of 32 bytes: 130µs at 8mA and 160.5µs at 11.3mA each
+        let traffic = Traffic {
+            packets: 10,
+            payload_len: 32,
+            ..TRAFFIC
+        };
+        let estimate = estimator.estimate(&traffic).unwrap();
+        assert_eq!(estimate.active_us, 2905);
+        let charge = 10 * (130 * 8_000_000 + 160_500 * 11_300) + (1_000_000 - 2905) * 900;
+        assert_eq!(estimate.average_na, charge / 1_000_000);
+
+        // Each retransmit costs another attempt
+        let retransmitted = estimator
+            .estimate(&Traffic {
+                retransmits: 1,
+                ..traffic
+            })
+            .unwrap();
+        assert_eq!(retransmitted.active_us, 2 * estimate.active_us);
+
+        assert_eq!(
+            estimator.estimate(&Traffic {
+                payload_len: 33,
+                ..traffic
+            }),
+            Err(Error::Airtime(esb::Error::PayloadTooLong))
+        );
+        assert_eq!(
+            estimator.estimate(&Traffic {
+                rx_us: 999_000,
+                ..traffic
+            }),
+            Err(Error::PeriodTooShort)
+        );
+    }
+}
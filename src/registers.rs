@@ -29,23 +29,62 @@
 //!     .with_prim_rx(false);
 //! assert_eq!(reg.into_bits(), 0b0100_0110);
 //! ```
-use crate::fields::{self, EnumField};
+use crate::fields;
 use bitfield_struct::bitfield;
 
 /// A trait for nRF24L01+ registers.
-#[const_trait]
+#[cfg_attr(feature = "nightly", const_trait)]
 pub trait Register: Copy {
     /// Register address.
     const ADDRESS: u8;
     /// Convert register to bits.
     fn into_bits(self) -> u8;
+    /// Convert bits to register.
+    fn from_bits(bits: u8) -> Self;
+}
+
+/// A register that can be read with [`R_REGISTER`](crate::commands::RRegister).
+pub trait ReadableRegister: Register {}
+
+/// A register that can be written with [`W_REGISTER`](crate::commands::WRegister).
+///
+/// Read-only registers, e.g. [`FifoStatus`], don't implement this trait, so writing them fails
+/// to compile.
+pub trait WritableRegister: Register {
+    /// Bits that are written. Read-only fields and reserved bits of registers with such fields
+    /// are cleared from the written byte.
+    const WRITE_MASK: u8 = 0xFF;
+}
+
+/// Implement `try_from_bits` on a single byte register whose bits outside of `mask` are reserved,
+/// or on a register without reserved bits, where it never fails.
+macro_rules! impl_try_from_bits {
+    ($reg:ident) => {
+        impl $reg {
+            /// Convert bits to the register. Never fails, as every bit is a field.
+            pub const fn try_from_bits(bits: u8) -> Result<Self, fields::InvalidBits> {
+                Ok(Self::from_bits(bits))
+            }
+        }
+    };
+    ($reg:ident, $mask:literal) => {
+        impl $reg {
+            /// Convert bits to the register, failing if reserved bits are set.
+            pub const fn try_from_bits(bits: u8) -> Result<Self, fields::InvalidBits> {
+                match fields::check_reserved(bits, $mask) {
+                    Ok(()) => Ok(Self::from_bits(bits)),
+                    Err(e) => Err(e),
+                }
+            }
+        }
+    };
 }
 
 /// A trait for nRF24L01+ address registers which can be 3-5 bytes.
 /// - RxAddrP0
 /// - RxAddrP1
 /// - TxAddr
-#[const_trait]
+#[cfg_attr(feature = "nightly", const_trait)]
 pub trait AddressRegister<const N: usize>: Copy {
     /// Register address.
     const ADDRESS: u8;
@@ -57,6 +96,8 @@ pub trait AddressRegister<const N: usize>: Copy {
     fn into_bits(self) -> u64;
     /// Convert into bytes ordered by LSByte first.
     fn into_bytes(self) -> [u8; N];
+    /// Convert from bytes ordered by LSByte first.
+    fn from_bytes(bytes: [u8; N]) -> Self;
 }
 
 /// # CONFIG register
@@ -179,14 +220,26 @@ pub struct Config {
     pub prim_rx: bool,
 }
 
-impl const Register for Config {
-    const ADDRESS: u8 = 0x00;
+impl_try_from_bits!(Config, 0b0111_1111);
 
-    fn into_bits(self) -> u8 {
-        self.into_bits()
+const_impl! {
+    impl Register for Config {
+        const ADDRESS: u8 = 0x00;
+
+        fn into_bits(self) -> u8 {
+            self.into_bits()
+        }
+
+        fn from_bits(bits: u8) -> Self {
+            Self::from_bits(bits)
+        }
     }
 }
 
+impl ReadableRegister for Config {}
+
+impl WritableRegister for Config {}
+
 /// # EN_AA register
 /// Enable 'Auto Acknowledgement' on data pipes 0-5.
 ///
@@ -240,14 +293,26 @@ pub struct EnAa {
     pub enaa_p0: bool,
 }
 
-impl const Register for EnAa {
-    const ADDRESS: u8 = 0x01;
+impl_try_from_bits!(EnAa, 0b0011_1111);
+
+const_impl! {
+    impl Register for EnAa {
+        const ADDRESS: u8 = 0x01;
+
+        fn into_bits(self) -> u8 {
+            self.into_bits()
+        }
 
-    fn into_bits(self) -> u8 {
-        self.into_bits()
+        fn from_bits(bits: u8) -> Self {
+            Self::from_bits(bits)
+        }
     }
 }
 
+impl ReadableRegister for EnAa {}
+
+impl WritableRegister for EnAa {}
+
 /// # EN_RXADDR register
 /// Enable RX address on data pipes 0-5.
 ///
@@ -302,14 +367,26 @@ pub struct EnRxaddr {
     pub erx_p0: bool,
 }
 
-impl const Register for EnRxaddr {
-    const ADDRESS: u8 = 0x02;
+impl_try_from_bits!(EnRxaddr, 0b0011_1111);
+
+const_impl! {
+    impl Register for EnRxaddr {
+        const ADDRESS: u8 = 0x02;
 
-    fn into_bits(self) -> u8 {
-        self.into_bits()
+        fn into_bits(self) -> u8 {
+            self.into_bits()
+        }
+
+        fn from_bits(bits: u8) -> Self {
+            Self::from_bits(bits)
+        }
     }
 }
 
+impl ReadableRegister for EnRxaddr {}
+
+impl WritableRegister for EnRxaddr {}
+
 /// # SETUP_AW register
 /// Set up address width. This applies to [`TxAddr`] and all RX addresses for data pipes.
 ///
@@ -344,14 +421,35 @@ pub struct SetupAw {
     pub aw: fields::AddressWidth,
 }
 
-impl const Register for SetupAw {
-    const ADDRESS: u8 = 0x03;
+impl SetupAw {
+    /// Convert bits to the register, failing if reserved bits are set or `aw` is
+    /// [`Illegal`](fields::AddressWidth::Illegal).
+    pub const fn try_from_bits(bits: u8) -> Result<Self, fields::InvalidBits> {
+        match fields::AddressWidth::try_from_bits(bits) {
+            Ok(_) => Ok(Self::from_bits(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+const_impl! {
+    impl Register for SetupAw {
+        const ADDRESS: u8 = 0x03;
 
-    fn into_bits(self) -> u8 {
-        self.into_bits()
+        fn into_bits(self) -> u8 {
+            self.into_bits()
+        }
+
+        fn from_bits(bits: u8) -> Self {
+            Self::from_bits(bits)
+        }
     }
 }
 
+impl ReadableRegister for SetupAw {}
+
+impl WritableRegister for SetupAw {}
+
 /// # SETUP_RETR register
 /// Set up 'Automatic Retransmission'.
 ///
@@ -410,14 +508,26 @@ pub struct SetupRetr {
     pub arc: u8,
 }
 
-impl const Register for SetupRetr {
-    const ADDRESS: u8 = 0x04;
+impl_try_from_bits!(SetupRetr);
 
-    fn into_bits(self) -> u8 {
-        self.into_bits()
+const_impl! {
+    impl Register for SetupRetr {
+        const ADDRESS: u8 = 0x04;
+
+        fn into_bits(self) -> u8 {
+            self.into_bits()
+        }
+
+        fn from_bits(bits: u8) -> Self {
+            Self::from_bits(bits)
+        }
     }
 }
 
+impl ReadableRegister for SetupRetr {}
+
+impl WritableRegister for SetupRetr {}
+
 /// # RF_CH register
 /// Set RF channel.
 ///
@@ -449,14 +559,26 @@ pub struct RfCh {
     pub rf_ch: u8,
 }
 
-impl const Register for RfCh {
-    const ADDRESS: u8 = 0x05;
+impl_try_from_bits!(RfCh, 0b0111_1111);
+
+const_impl! {
+    impl Register for RfCh {
+        const ADDRESS: u8 = 0x05;
+
+        fn into_bits(self) -> u8 {
+            self.into_bits()
+        }
 
-    fn into_bits(self) -> u8 {
-        self.into_bits()
+        fn from_bits(bits: u8) -> Self {
+            Self::from_bits(bits)
+        }
     }
 }
 
+impl ReadableRegister for RfCh {}
+
+impl WritableRegister for RfCh {}
+
 /// # RF_SETUP register
 /// Set RF air data rate and output power.
 ///
@@ -467,7 +589,8 @@ impl const Register for RfCh {
 /// Enables continuous carrier transmit.
 ///
 /// #### `rf_dr_low` | bit 5
-/// Set RF data rate to 250kbps. See `rf_dr_high`.
+/// Set RF data rate to 250kbps. See `rf_dr_high`, and
+/// [`data_rate`](RfSetup::data_rate) to set both.
 ///
 /// #### `pll_lock` | bit 4
 /// Force PLL lock signal. Only used in test.
@@ -552,14 +675,92 @@ pub struct RfSetup {
     __: bool,
 }
 
-impl const Register for RfSetup {
-    const ADDRESS: u8 = 0x06;
+impl RfSetup {
+    /// Data rate encoded by `rf_dr_low` and `rf_dr_high`, failing on the reserved combination
+    /// with both bits set.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use nrf24l01_commands::{fields, registers};
+    ///
+    /// let reg = registers::RfSetup::new();
+    /// assert_eq!(reg.data_rate(), Ok(fields::DataRate::Mbps2));
+    ///
+    /// let reg = reg.with_data_rate(fields::DataRate::Kbps250);
+    /// assert_eq!(reg.into_bits(), 0b0010_0110);
+    /// assert_eq!(reg.data_rate(), Ok(fields::DataRate::Kbps250));
+    ///
+    /// let reserved = registers::RfSetup::from_bits(0b0010_1110);
+    /// assert!(reserved.data_rate().is_err());
+    /// ```
+    pub const fn data_rate(&self) -> Result<fields::DataRate, fields::InvalidBits> {
+        match (self.rf_dr_low(), self.rf_dr_high()) {
+            (false, fields::RfDrHigh::Mbps1) => Ok(fields::DataRate::Mbps1),
+            (false, fields::RfDrHigh::Mbps2) => Ok(fields::DataRate::Mbps2),
+            (true, fields::RfDrHigh::Mbps1) => Ok(fields::DataRate::Kbps250),
+            (true, fields::RfDrHigh::Mbps2) => Err(fields::InvalidBits::Value {
+                field: "DataRate",
+                bits: 0b11,
+            }),
+        }
+    }
+
+    /// Convert bits to the register, failing if reserved bits are set or `rf_dr_low` and
+    /// `rf_dr_high` are both set.
+    pub const fn try_from_bits(bits: u8) -> Result<Self, fields::InvalidBits> {
+        if let Err(e) = fields::check_reserved(bits, 0b1011_1110) {
+            return Err(e);
+        }
+        let reg = Self::from_bits(bits);
+        match reg.data_rate() {
+            Ok(_) => Ok(reg),
+            Err(e) => Err(e),
+        }
+    }
 
-    fn into_bits(self) -> u8 {
-        self.into_bits()
+    /// Data rate, taking the reserved combination as 250kbps since `rf_dr_high` is don't care
+    /// if `rf_dr_low` is set.
+    pub(crate) const fn effective_data_rate(&self) -> fields::DataRate {
+        match self.data_rate() {
+            Ok(data_rate) => data_rate,
+            Err(_) => fields::DataRate::Kbps250,
+        }
+    }
+
+    /// Set `rf_dr_low` and `rf_dr_high` to `data_rate`.
+    pub const fn with_data_rate(self, data_rate: fields::DataRate) -> Self {
+        let (low, high) = match data_rate {
+            fields::DataRate::Kbps250 => (true, fields::RfDrHigh::Mbps1),
+            fields::DataRate::Mbps1 => (false, fields::RfDrHigh::Mbps1),
+            fields::DataRate::Mbps2 => (false, fields::RfDrHigh::Mbps2),
+        };
+        self.with_rf_dr_low(low).with_rf_dr_high(high)
+    }
+
+    /// Set `rf_dr_low` and `rf_dr_high` to `data_rate`.
+    pub const fn set_data_rate(&mut self, data_rate: fields::DataRate) {
+        *self = self.with_data_rate(data_rate);
     }
 }
 
+const_impl! {
+    impl Register for RfSetup {
+        const ADDRESS: u8 = 0x06;
+
+        fn into_bits(self) -> u8 {
+            self.into_bits()
+        }
+
+        fn from_bits(bits: u8) -> Self {
+            Self::from_bits(bits)
+        }
+    }
+}
+
+impl ReadableRegister for RfSetup {}
+
+impl WritableRegister for RfSetup {}
+
 /// # STATUS register
 ///
 /// Address = `0x07`
@@ -639,14 +840,107 @@ pub struct Status {
     pub tx_full: bool,
 }
 
-impl const Register for Status {
-    const ADDRESS: u8 = 0x07;
+impl Status {
+    /// Convert bits to the register, failing if the reserved bit 7 is set or `rx_p_no` is
+    /// the reserved value `0b110`, e.g. because MISO is stuck high.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use nrf24l01_commands::{fields::InvalidBits, registers};
+    ///
+    /// assert!(registers::Status::try_from_bits(0b0100_0010).is_ok());
+    /// assert_eq!(
+    ///     registers::Status::try_from_bits(0xFF).unwrap_err(),
+    ///     InvalidBits::Reserved { bits: 0b1000_0000 }
+    /// );
+    /// assert_eq!(
+    ///     registers::Status::try_from_bits(0b0000_1100).unwrap_err(),
+    ///     InvalidBits::Value { field: "RxPipeNo", bits: 0b110 }
+    /// );
+    /// ```
+    pub const fn try_from_bits(bits: u8) -> Result<Self, fields::InvalidBits> {
+        if let Err(e) = fields::check_reserved(bits, 0b0111_1111) {
+            return Err(e);
+        }
+        match fields::RxPipeNo::try_from_bits((bits >> 1) & 0b111) {
+            Ok(_) => Ok(Self::from_bits(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Interrupt flags that are set, i.e. the causes of the IRQ.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use nrf24l01_commands::{fields::Interrupt, registers};
+    ///
+    /// let status = registers::Status::from_bits(0b0101_0010);
+    /// let mut interrupts = status.interrupts();
+    /// assert_eq!(interrupts.next(), Some(Interrupt::RxDr));
+    /// assert_eq!(interrupts.next(), Some(Interrupt::MaxRt));
+    /// assert_eq!(interrupts.next(), None);
+    /// ```
+    pub const fn interrupts(&self) -> Interrupts {
+        Interrupts(self.into_bits() & Interrupts::MASK)
+    }
+}
+
+/// Interrupt flags set in a [`Status`], iterated from `rx_dr` to `max_rt`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Interrupts(u8);
+
+impl Interrupts {
+    /// Bits of the interrupt flags.
+    const MASK: u8 = 0b0111_0000;
+
+    /// Whether `interrupt` is set.
+    pub const fn contains(&self, interrupt: fields::Interrupt) -> bool {
+        self.0 & interrupt.mask() != 0
+    }
+
+    /// Whether no interrupt flag is set.
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl Iterator for Interrupts {
+    type Item = fields::Interrupt;
+
+    fn next(&mut self) -> Option<fields::Interrupt> {
+        let interrupt = [
+            fields::Interrupt::RxDr,
+            fields::Interrupt::TxDs,
+            fields::Interrupt::MaxRt,
+        ]
+        .into_iter()
+        .find(|&interrupt| self.contains(interrupt))?;
+        self.0 &= !interrupt.mask();
+        Some(interrupt)
+    }
+}
+
+const_impl! {
+    impl Register for Status {
+        const ADDRESS: u8 = 0x07;
+
+        fn into_bits(self) -> u8 {
+            self.into_bits()
+        }
 
-    fn into_bits(self) -> u8 {
-        self.into_bits()
+        fn from_bits(bits: u8) -> Self {
+            Self::from_bits(bits)
+        }
     }
 }
 
+impl ReadableRegister for Status {}
+
+impl WritableRegister for Status {
+    /// Only the interrupt flags, which are cleared by writing 1.
+    const WRITE_MASK: u8 = 0b0111_0000;
+}
+
 /// # OBSERVE_TX register
 /// Transmit observe register.
 ///
@@ -688,14 +982,24 @@ pub struct ObserveTx {
     pub arc_cnt: u8,
 }
 
-impl const Register for ObserveTx {
-    const ADDRESS: u8 = 0x08;
+impl_try_from_bits!(ObserveTx);
 
-    fn into_bits(self) -> u8 {
-        self.into_bits()
+const_impl! {
+    impl Register for ObserveTx {
+        const ADDRESS: u8 = 0x08;
+
+        fn into_bits(self) -> u8 {
+            self.into_bits()
+        }
+
+        fn from_bits(bits: u8) -> Self {
+            Self::from_bits(bits)
+        }
     }
 }
 
+impl ReadableRegister for ObserveTx {}
+
 /// # RPD register
 /// Received power detector.
 ///
@@ -726,14 +1030,24 @@ pub struct Rpd {
     pub rpd: bool,
 }
 
-impl const Register for Rpd {
-    const ADDRESS: u8 = 0x09;
+impl_try_from_bits!(Rpd, 0b0000_0001);
 
-    fn into_bits(self) -> u8 {
-        self.into_bits()
+const_impl! {
+    impl Register for Rpd {
+        const ADDRESS: u8 = 0x09;
+
+        fn into_bits(self) -> u8 {
+            self.into_bits()
+        }
+
+        fn from_bits(bits: u8) -> Self {
+            Self::from_bits(bits)
+        }
     }
 }
 
+impl ReadableRegister for Rpd {}
+
 /// # RX_ADDR_P0 register
 /// RX address data pipe 0.
 ///
@@ -794,27 +1108,71 @@ const fn address_into_bytes<const N: usize>(addr: u64) -> [u8; N] {
     bytes
 }
 
-impl<const N: usize> const AddressRegister<N> for RxAddrP0<N> {
-    const ADDRESS: u8 = 0x0A;
+/// Convert little-endian bytes to u64 address.
+/// Const parameter `N`: address width in bytes. Constraint: `N` in {3, 4, 5}.
+#[inline(always)]
+const fn address_from_bytes<const N: usize>(bytes: [u8; N]) -> u64 {
+    let mut le_bytes = [0; 8];
+    let mut i = 0;
+    while i < N {
+        le_bytes[i] = bytes[i];
+        i += 1;
+    }
+    u64::from_le_bytes(le_bytes)
+}
+
+const_impl! {
+    impl<const N: usize> AddressRegister<N> for RxAddrP0<N> {
+        const ADDRESS: u8 = 0x0A;
+
+        fn new() -> Self {
+            Self::new()
+        }
+
+        fn from_bits(bits: u64) -> Self {
+            Self::from_bits(bits)
+        }
+
+        fn into_bits(self) -> u64 {
+            self.into_bits()
+        }
+
+        fn into_bytes(self) -> [u8; N] {
+            self.into_bytes()
+        }
+
+        fn from_bytes(bytes: [u8; N]) -> Self {
+            Self::from_bytes(bytes)
+        }
+    }
+}
 
-    fn new() -> Self {
+impl<const N: usize> RxAddrP0<N> {
+    /// Creates a new default initialized bitfield.
+    pub const fn new() -> Self {
         Self(RxAddrP0Fields::new())
     }
 
-    fn from_bits(bits: u64) -> Self {
+    /// Convert from bits.
+    pub const fn from_bits(bits: u64) -> Self {
         Self(RxAddrP0Fields::from_bits(bits))
     }
 
-    fn into_bits(self) -> u64 {
+    /// Convert into bits.
+    pub const fn into_bits(self) -> u64 {
         self.0.into_bits()
     }
 
-    fn into_bytes(self) -> [u8; N] {
+    /// Convert into bytes ordered by LSByte first.
+    pub const fn into_bytes(self) -> [u8; N] {
         address_into_bytes(self.0.0)
     }
-}
 
-impl<const N: usize> RxAddrP0<N> {
+    /// Convert from bytes ordered by LSByte first.
+    pub const fn from_bytes(bytes: [u8; N]) -> Self {
+        Self::from_bits(address_from_bytes(bytes))
+    }
+
     /// RX address data pipe 0. Default value: `0xE7E7E7E7E7`.
     pub const fn rx_addr_p0(&self) -> u64 {
         self.0.rx_addr_p0()
@@ -879,27 +1237,58 @@ struct RxAddrP1Fields {
     rx_addr_p1: u64,
 }
 
-impl<const N: usize> const AddressRegister<N> for RxAddrP1<N> {
-    const ADDRESS: u8 = 0x0B;
+const_impl! {
+    impl<const N: usize> AddressRegister<N> for RxAddrP1<N> {
+        const ADDRESS: u8 = 0x0B;
+
+        fn new() -> Self {
+            Self::new()
+        }
+
+        fn from_bits(bits: u64) -> Self {
+            Self::from_bits(bits)
+        }
 
-    fn new() -> Self {
+        fn into_bits(self) -> u64 {
+            self.into_bits()
+        }
+
+        fn into_bytes(self) -> [u8; N] {
+            self.into_bytes()
+        }
+
+        fn from_bytes(bytes: [u8; N]) -> Self {
+            Self::from_bytes(bytes)
+        }
+    }
+}
+
+impl<const N: usize> RxAddrP1<N> {
+    /// Creates a new default initialized bitfield.
+    pub const fn new() -> Self {
         Self(RxAddrP1Fields::new())
     }
 
-    fn from_bits(bits: u64) -> Self {
+    /// Convert from bits.
+    pub const fn from_bits(bits: u64) -> Self {
         Self(RxAddrP1Fields::from_bits(bits))
     }
 
-    fn into_bits(self) -> u64 {
+    /// Convert into bits.
+    pub const fn into_bits(self) -> u64 {
         self.0.into_bits()
     }
 
-    fn into_bytes(self) -> [u8; N] {
+    /// Convert into bytes ordered by LSByte first.
+    pub const fn into_bytes(self) -> [u8; N] {
         address_into_bytes(self.0.0)
     }
-}
 
-impl<const N: usize> RxAddrP1<N> {
+    /// Convert from bytes ordered by LSByte first.
+    pub const fn from_bytes(bytes: [u8; N]) -> Self {
+        Self::from_bits(address_from_bytes(bytes))
+    }
+
     /// RX address data pipe 1. Default value: `0xC2C2C2C2C2`.
     pub const fn rx_addr_p1(&self) -> u64 {
         self.0.rx_addr_p1()
@@ -947,14 +1336,26 @@ pub struct RxAddrP2 {
     pub rx_addr_p2: u8,
 }
 
-impl const Register for RxAddrP2 {
-    const ADDRESS: u8 = 0x0C;
+impl_try_from_bits!(RxAddrP2);
+
+const_impl! {
+    impl Register for RxAddrP2 {
+        const ADDRESS: u8 = 0x0C;
 
-    fn into_bits(self) -> u8 {
-        self.into_bits()
+        fn into_bits(self) -> u8 {
+            self.into_bits()
+        }
+
+        fn from_bits(bits: u8) -> Self {
+            Self::from_bits(bits)
+        }
     }
 }
 
+impl ReadableRegister for RxAddrP2 {}
+
+impl WritableRegister for RxAddrP2 {}
+
 /// # RX_ADDR_P3 register
 /// RX address data pipe 3. Only LSByte is stored.
 /// MSBytes are equal to [`RxAddrP1`] bits 39:8.
@@ -984,14 +1385,26 @@ pub struct RxAddrP3 {
     pub rx_addr_p3: u8,
 }
 
-impl const Register for RxAddrP3 {
-    const ADDRESS: u8 = 0x0D;
+impl_try_from_bits!(RxAddrP3);
 
-    fn into_bits(self) -> u8 {
-        self.into_bits()
+const_impl! {
+    impl Register for RxAddrP3 {
+        const ADDRESS: u8 = 0x0D;
+
+        fn into_bits(self) -> u8 {
+            self.into_bits()
+        }
+
+        fn from_bits(bits: u8) -> Self {
+            Self::from_bits(bits)
+        }
     }
 }
 
+impl ReadableRegister for RxAddrP3 {}
+
+impl WritableRegister for RxAddrP3 {}
+
 /// # RX_ADDR_P4 register
 /// RX address data pipe 4. Only LSByte is stored.
 /// MSBytes are equal to [`RxAddrP1`] bits 39:8.
@@ -1021,14 +1434,26 @@ pub struct RxAddrP4 {
     pub rx_addr_p4: u8,
 }
 
-impl const Register for RxAddrP4 {
-    const ADDRESS: u8 = 0x0E;
+impl_try_from_bits!(RxAddrP4);
+
+const_impl! {
+    impl Register for RxAddrP4 {
+        const ADDRESS: u8 = 0x0E;
+
+        fn into_bits(self) -> u8 {
+            self.into_bits()
+        }
 
-    fn into_bits(self) -> u8 {
-        self.into_bits()
+        fn from_bits(bits: u8) -> Self {
+            Self::from_bits(bits)
+        }
     }
 }
 
+impl ReadableRegister for RxAddrP4 {}
+
+impl WritableRegister for RxAddrP4 {}
+
 /// # RX_ADDR_P5 register
 /// RX address data pipe 5. Only LSByte is stored.
 /// MSBytes are equal to [`RxAddrP1`] bits 39:8.
@@ -1058,14 +1483,26 @@ pub struct RxAddrP5 {
     pub rx_addr_p5: u8,
 }
 
-impl const Register for RxAddrP5 {
-    const ADDRESS: u8 = 0x0F;
+impl_try_from_bits!(RxAddrP5);
+
+const_impl! {
+    impl Register for RxAddrP5 {
+        const ADDRESS: u8 = 0x0F;
 
-    fn into_bits(self) -> u8 {
-        self.into_bits()
+        fn into_bits(self) -> u8 {
+            self.into_bits()
+        }
+
+        fn from_bits(bits: u8) -> Self {
+            Self::from_bits(bits)
+        }
     }
 }
 
+impl ReadableRegister for RxAddrP5 {}
+
+impl WritableRegister for RxAddrP5 {}
+
 /// # TX_ADDR register
 /// TX address. Set [`RxAddrP0`] equal to this address to handle ACK automatically.
 ///
@@ -1112,27 +1549,58 @@ struct TxAddrFields {
     tx_addr: u64,
 }
 
-impl<const N: usize> const AddressRegister<N> for TxAddr<N> {
-    const ADDRESS: u8 = 0x10;
+const_impl! {
+    impl<const N: usize> AddressRegister<N> for TxAddr<N> {
+        const ADDRESS: u8 = 0x10;
 
-    fn new() -> Self {
+        fn new() -> Self {
+            Self::new()
+        }
+
+        fn from_bits(bits: u64) -> Self {
+            Self::from_bits(bits)
+        }
+
+        fn into_bits(self) -> u64 {
+            self.into_bits()
+        }
+
+        fn into_bytes(self) -> [u8; N] {
+            self.into_bytes()
+        }
+
+        fn from_bytes(bytes: [u8; N]) -> Self {
+            Self::from_bytes(bytes)
+        }
+    }
+}
+
+impl<const N: usize> TxAddr<N> {
+    /// Creates a new default initialized bitfield.
+    pub const fn new() -> Self {
         Self(TxAddrFields::new())
     }
 
-    fn from_bits(bits: u64) -> Self {
+    /// Convert from bits.
+    pub const fn from_bits(bits: u64) -> Self {
         Self(TxAddrFields::from_bits(bits))
     }
 
-    fn into_bits(self) -> u64 {
+    /// Convert into bits.
+    pub const fn into_bits(self) -> u64 {
         self.0.into_bits()
     }
 
-    fn into_bytes(self) -> [u8; N] {
+    /// Convert into bytes ordered by LSByte first.
+    pub const fn into_bytes(self) -> [u8; N] {
         address_into_bytes(self.0.0)
     }
-}
 
-impl<const N: usize> TxAddr<N> {
+    /// Convert from bytes ordered by LSByte first.
+    pub const fn from_bytes(bytes: [u8; N]) -> Self {
+        Self::from_bits(address_from_bytes(bytes))
+    }
+
     /// TX address. Default value: `0xE7E7E7E7E7`.
     pub const fn tx_addr(&self) -> u64 {
         self.0.tx_addr()
@@ -1182,14 +1650,26 @@ pub struct RxPwP0 {
     pub rx_pw_p0: u8,
 }
 
-impl const Register for RxPwP0 {
-    const ADDRESS: u8 = 0x11;
+impl_try_from_bits!(RxPwP0, 0b0011_1111);
+
+const_impl! {
+    impl Register for RxPwP0 {
+        const ADDRESS: u8 = 0x11;
 
-    fn into_bits(self) -> u8 {
-        self.into_bits()
+        fn into_bits(self) -> u8 {
+            self.into_bits()
+        }
+
+        fn from_bits(bits: u8) -> Self {
+            Self::from_bits(bits)
+        }
     }
 }
 
+impl ReadableRegister for RxPwP0 {}
+
+impl WritableRegister for RxPwP0 {}
+
 /// # RX_PW_P1 register
 /// RX payload width for data pipe 1.
 ///
@@ -1221,14 +1701,26 @@ pub struct RxPwP1 {
     pub rx_pw_p1: u8,
 }
 
-impl const Register for RxPwP1 {
-    const ADDRESS: u8 = 0x12;
+impl_try_from_bits!(RxPwP1, 0b0011_1111);
 
-    fn into_bits(self) -> u8 {
-        self.into_bits()
+const_impl! {
+    impl Register for RxPwP1 {
+        const ADDRESS: u8 = 0x12;
+
+        fn into_bits(self) -> u8 {
+            self.into_bits()
+        }
+
+        fn from_bits(bits: u8) -> Self {
+            Self::from_bits(bits)
+        }
     }
 }
 
+impl ReadableRegister for RxPwP1 {}
+
+impl WritableRegister for RxPwP1 {}
+
 /// # RX_PW_P2 register
 /// RX payload width for data pipe 2.
 ///
@@ -1260,14 +1752,26 @@ pub struct RxPwP2 {
     pub rx_pw_p2: u8,
 }
 
-impl const Register for RxPwP2 {
-    const ADDRESS: u8 = 0x13;
+impl_try_from_bits!(RxPwP2, 0b0011_1111);
+
+const_impl! {
+    impl Register for RxPwP2 {
+        const ADDRESS: u8 = 0x13;
+
+        fn into_bits(self) -> u8 {
+            self.into_bits()
+        }
 
-    fn into_bits(self) -> u8 {
-        self.into_bits()
+        fn from_bits(bits: u8) -> Self {
+            Self::from_bits(bits)
+        }
     }
 }
 
+impl ReadableRegister for RxPwP2 {}
+
+impl WritableRegister for RxPwP2 {}
+
 /// # RX_PW_P3 register
 /// RX payload width for data pipe 3.
 ///
@@ -1299,14 +1803,26 @@ pub struct RxPwP3 {
     pub rx_pw_p3: u8,
 }
 
-impl const Register for RxPwP3 {
-    const ADDRESS: u8 = 0x14;
+impl_try_from_bits!(RxPwP3, 0b0011_1111);
 
-    fn into_bits(self) -> u8 {
-        self.into_bits()
+const_impl! {
+    impl Register for RxPwP3 {
+        const ADDRESS: u8 = 0x14;
+
+        fn into_bits(self) -> u8 {
+            self.into_bits()
+        }
+
+        fn from_bits(bits: u8) -> Self {
+            Self::from_bits(bits)
+        }
     }
 }
 
+impl ReadableRegister for RxPwP3 {}
+
+impl WritableRegister for RxPwP3 {}
+
 /// # RX_PW_P4 register
 /// RX payload width for data pipe 4.
 ///
@@ -1338,14 +1854,26 @@ pub struct RxPwP4 {
     pub rx_pw_p4: u8,
 }
 
-impl const Register for RxPwP4 {
-    const ADDRESS: u8 = 0x15;
+impl_try_from_bits!(RxPwP4, 0b0011_1111);
+
+const_impl! {
+    impl Register for RxPwP4 {
+        const ADDRESS: u8 = 0x15;
+
+        fn into_bits(self) -> u8 {
+            self.into_bits()
+        }
 
-    fn into_bits(self) -> u8 {
-        self.into_bits()
+        fn from_bits(bits: u8) -> Self {
+            Self::from_bits(bits)
+        }
     }
 }
 
+impl ReadableRegister for RxPwP4 {}
+
+impl WritableRegister for RxPwP4 {}
+
 /// # RX_PW_P5 register
 /// RX payload width for data pipe 5.
 ///
@@ -1377,14 +1905,26 @@ pub struct RxPwP5 {
     pub rx_pw_p5: u8,
 }
 
-impl const Register for RxPwP5 {
-    const ADDRESS: u8 = 0x16;
+impl_try_from_bits!(RxPwP5, 0b0011_1111);
+
+const_impl! {
+    impl Register for RxPwP5 {
+        const ADDRESS: u8 = 0x16;
 
-    fn into_bits(self) -> u8 {
-        self.into_bits()
+        fn into_bits(self) -> u8 {
+            self.into_bits()
+        }
+
+        fn from_bits(bits: u8) -> Self {
+            Self::from_bits(bits)
+        }
     }
 }
 
+impl ReadableRegister for RxPwP5 {}
+
+impl WritableRegister for RxPwP5 {}
+
 /// # FIFO_STATUS register
 /// Status of TX/RX FIFOs.
 ///
@@ -1459,14 +1999,24 @@ pub struct FifoStatus {
     pub rx_empty: bool,
 }
 
-impl const Register for FifoStatus {
-    const ADDRESS: u8 = 0x17;
+impl_try_from_bits!(FifoStatus, 0b0111_0011);
 
-    fn into_bits(self) -> u8 {
-        self.into_bits()
+const_impl! {
+    impl Register for FifoStatus {
+        const ADDRESS: u8 = 0x17;
+
+        fn into_bits(self) -> u8 {
+            self.into_bits()
+        }
+
+        fn from_bits(bits: u8) -> Self {
+            Self::from_bits(bits)
+        }
     }
 }
 
+impl ReadableRegister for FifoStatus {}
+
 /// # DYNPD register
 /// Enable dynamic payload length for data pipes 0-5.
 ///
@@ -1526,14 +2076,26 @@ pub struct Dynpd {
     pub dpl_p0: bool,
 }
 
-impl const Register for Dynpd {
-    const ADDRESS: u8 = 0x1C;
+impl_try_from_bits!(Dynpd, 0b0011_1111);
 
-    fn into_bits(self) -> u8 {
-        self.into_bits()
+const_impl! {
+    impl Register for Dynpd {
+        const ADDRESS: u8 = 0x1C;
+
+        fn into_bits(self) -> u8 {
+            self.into_bits()
+        }
+
+        fn from_bits(bits: u8) -> Self {
+            Self::from_bits(bits)
+        }
     }
 }
 
+impl ReadableRegister for Dynpd {}
+
+impl WritableRegister for Dynpd {}
+
 /// # FEATURE register
 /// Enable features _Dynamic Payload Length_, _Payload with ACK_ and `W_TX_PAYLOAD_NO_ACK` command.
 ///
@@ -1582,10 +2144,22 @@ pub struct Feature {
     pub en_dyn_ack: bool,
 }
 
-impl const Register for Feature {
-    const ADDRESS: u8 = 0x1D;
+impl_try_from_bits!(Feature, 0b0000_0111);
+
+const_impl! {
+    impl Register for Feature {
+        const ADDRESS: u8 = 0x1D;
+
+        fn into_bits(self) -> u8 {
+            self.into_bits()
+        }
 
-    fn into_bits(self) -> u8 {
-        self.into_bits()
+        fn from_bits(bits: u8) -> Self {
+            Self::from_bits(bits)
+        }
     }
 }
+
+impl ReadableRegister for Feature {}
+
+impl WritableRegister for Feature {}
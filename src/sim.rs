@@ -0,0 +1,841 @@
+//! Software simulator of the nRF24L01+ register file and FIFOs.
+//!
+//! Requires the `sim` feature.
+//!
+//! [`Nrf24l01`] decodes the SPI byte stream of the commands in [`commands`] and models
+//! the register file, the 3-level TX and RX FIFOs and the [`STATUS`][registers::Status]
+//! and [`FIFO_STATUS`][registers::FifoStatus] flags. Registers reset to the values of
+//! `registers::*::new()`.
+//!
+//! The simulator hands out [`Spi`], [`Ce`] and [`Irq`] handles that implement the
+//! `embedded-hal` SPI device and pin traits, so drivers run against it unchanged.
+//!
+//! Several chips can be connected over the air with a [`Medium`].
+//!
+//! ## Example
+//! ```rust
+//! use nrf24l01_commands::{commands, registers, sim, spi::SpiDeviceExt};
+//!
+//! let chip = sim::Nrf24l01::new();
+//! let mut spi = chip.spi();
+//!
+//! // Write RF_CH register
+//! let rf_ch = registers::RfCh::new().with_rf_ch(76);
+//! spi.execute(commands::WRegister(rf_ch)).unwrap();
+//! assert_eq!(chip.register::<registers::RfCh>().rf_ch(), 76);
+//!
+//! // Fill the TX FIFO
+//! for i in 0..3 {
+//!     spi.execute(commands::WTxPayload([i; 4])).unwrap();
+//! }
+//! let status = spi.execute(commands::Nop()).unwrap();
+//! assert!(status.tx_full());
+//! ```
+use crate::commands::{self, Command, MAX_PAYLOAD_WIDTH};
+use crate::fields;
+use crate::registers::{self, AddressRegister, Register};
+use core::cell::RefCell;
+use core::convert::Infallible;
+use embedded_hal::digital::{self, InputPin, OutputPin};
+use embedded_hal::spi::{self, Operation, SpiDevice};
+
+mod medium;
+
+pub use medium::Medium;
+
+/// Depth of the TX and RX FIFOs.
+pub const FIFO_DEPTH: usize = 3;
+
+/// Number of single-byte register addresses, `0x00` to `0x1D`.
+const REGISTER_COUNT: usize = 0x1E;
+/// Maximum address width in bytes.
+const MAX_ADDRESS_WIDTH: usize = 5;
+
+/// STATUS bits that are cleared by writing 1.
+const STATUS_IRQ_MASK: u8 = 0b0111_0000;
+
+/// A payload of 1 to 32 bytes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Payload {
+    len: u8,
+    data: [u8; MAX_PAYLOAD_WIDTH],
+}
+
+impl Payload {
+    /// Create a payload from a slice. Bytes beyond 32 are discarded.
+    pub fn new(bytes: &[u8]) -> Self {
+        let len = bytes.len().min(MAX_PAYLOAD_WIDTH);
+        let mut data = [0; MAX_PAYLOAD_WIDTH];
+        data[..len].copy_from_slice(&bytes[..len]);
+        Self {
+            len: len as u8,
+            data,
+        }
+    }
+
+    /// Payload bytes.
+    pub fn as_slice(&self) -> &[u8] {
+        &self.data[..self.len as usize]
+    }
+
+    /// Payload width in bytes.
+    pub fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    /// Whether the payload is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn push(&mut self, byte: u8) {
+        if (self.len as usize) < MAX_PAYLOAD_WIDTH {
+            self.data[self.len as usize] = byte;
+            self.len += 1;
+        }
+    }
+}
+
+/// An entry of the TX FIFO.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TxEntry {
+    /// Payload.
+    pub payload: Payload,
+    /// Written with [`W_TX_PAYLOAD_NOACK`][commands::WTxPayloadNoack].
+    pub no_ack: bool,
+    /// Data pipe of a payload written with [`W_ACK_PAYLOAD`][commands::WAckPayload].
+    pub ack_pipe: Option<fields::Pipe>,
+}
+
+/// An entry of the RX FIFO.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RxEntry {
+    /// Data pipe the payload was received on.
+    pub pipe: fields::Pipe,
+    /// Payload.
+    pub payload: Payload,
+}
+
+/// Fixed-capacity FIFO.
+#[derive(Copy, Clone, Debug)]
+struct Fifo<T> {
+    entries: [Option<T>; FIFO_DEPTH],
+    head: usize,
+    len: usize,
+}
+
+impl<T: Copy> Fifo<T> {
+    const fn new() -> Self {
+        Self {
+            entries: [None; FIFO_DEPTH],
+            head: 0,
+            len: 0,
+        }
+    }
+
+    fn push(&mut self, entry: T) -> bool {
+        if self.is_full() {
+            return false;
+        }
+        self.entries[(self.head + self.len) % FIFO_DEPTH] = Some(entry);
+        self.len += 1;
+        true
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        let entry = self.peek()?;
+        self.entries[self.head] = None;
+        self.head = (self.head + 1) % FIFO_DEPTH;
+        self.len -= 1;
+        Some(entry)
+    }
+
+    fn peek(&self) -> Option<T> {
+        if self.len == 0 {
+            None
+        } else {
+            self.entries[self.head]
+        }
+    }
+
+    fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn is_full(&self) -> bool {
+        self.len == FIFO_DEPTH
+    }
+}
+
+/// Command being decoded in the current SPI transaction.
+#[derive(Copy, Clone, Debug)]
+enum Decode {
+    /// Waiting for the command word.
+    Word,
+    /// `R_REGISTER`, at byte `index` of the register.
+    ReadRegister { address: u8, index: usize },
+    /// `W_REGISTER`, collecting bytes.
+    WriteRegister {
+        address: u8,
+        bytes: [u8; MAX_ADDRESS_WIDTH],
+        len: usize,
+    },
+    /// `R_RX_PAYLOAD`, at byte `index` of the payload.
+    ReadRxPayload { index: usize },
+    /// `R_RX_PL_WID`.
+    ReadRxPayloadWidth,
+    /// `W_TX_PAYLOAD`, `W_TX_PAYLOAD_NOACK` or `W_ACK_PAYLOAD`, collecting the payload.
+    WritePayload(TxEntry),
+    /// Single byte command executed at the end of the transaction.
+    Single(u8),
+    /// Unsupported command, remaining bytes are ignored.
+    Ignore,
+}
+
+/// Chip state.
+#[derive(Clone, Debug)]
+struct Chip {
+    registers: [u8; REGISTER_COUNT],
+    rx_addr_p0: [u8; MAX_ADDRESS_WIDTH],
+    rx_addr_p1: [u8; MAX_ADDRESS_WIDTH],
+    tx_addr: [u8; MAX_ADDRESS_WIDTH],
+    tx_fifo: Fifo<TxEntry>,
+    rx_fifo: Fifo<RxEntry>,
+    tx_reuse: bool,
+    ce: bool,
+    /// CE was set high since the last transmission.
+    ce_pulse: bool,
+    /// PID of the last packet transmitted.
+    pid: u8,
+    /// The TX FIFO head was transmitted, retransmits of it keep its PID.
+    tx_head_sent: bool,
+    /// PID and payload of the last packet received on each pipe.
+    rx_last: [Option<(u8, Payload)>; fields::PIPES],
+    decode: Decode,
+}
+
+impl Chip {
+    fn new() -> Self {
+        let mut chip = Self {
+            registers: [0; REGISTER_COUNT],
+            rx_addr_p0: registers::RxAddrP0::<5>::new().into_bytes(),
+            rx_addr_p1: registers::RxAddrP1::<5>::new().into_bytes(),
+            tx_addr: registers::TxAddr::<5>::new().into_bytes(),
+            tx_fifo: Fifo::new(),
+            rx_fifo: Fifo::new(),
+            tx_reuse: false,
+            ce: false,
+            ce_pulse: false,
+            pid: 0,
+            tx_head_sent: false,
+            rx_last: [None; fields::PIPES],
+            decode: Decode::Word,
+        };
+        macro_rules! reset {
+            ($($reg:ident),*) => {
+                $(chip.registers[registers::$reg::ADDRESS as usize] = registers::$reg::new().into_bits();)*
+            };
+        }
+        reset!(
+            Config, EnAa, EnRxaddr, SetupAw, SetupRetr, RfCh, RfSetup, Status, ObserveTx, Rpd,
+            RxAddrP2, RxAddrP3, RxAddrP4, RxAddrP5, RxPwP0, RxPwP1, RxPwP2, RxPwP3, RxPwP4, RxPwP5,
+            FifoStatus, Dynpd, Feature
+        );
+        chip
+    }
+
+    fn register<R: Register>(&self) -> R {
+        R::from_bits(self.read_register(R::ADDRESS))
+    }
+
+    fn status(&self) -> registers::Status {
+        let rx_p_no = match self.rx_fifo.peek() {
+            Some(entry) => entry.pipe.into_bits(),
+            None => 0b111,
+        };
+        registers::Status::from_bits(
+            self.registers[registers::Status::ADDRESS as usize] & STATUS_IRQ_MASK
+                | rx_p_no << 1
+                | self.tx_fifo.is_full() as u8,
+        )
+    }
+
+    fn fifo_status(&self) -> registers::FifoStatus {
+        registers::FifoStatus::from_bits(
+            (self.tx_reuse as u8) << 6
+                | (self.tx_fifo.is_full() as u8) << 5
+                | (self.tx_fifo.is_empty() as u8) << 4
+                | (self.rx_fifo.is_full() as u8) << 1
+                | self.rx_fifo.is_empty() as u8,
+        )
+    }
+
+    /// Read a single-byte register or the LSByte of an address register.
+    fn read_register(&self, address: u8) -> u8 {
+        self.read_register_byte(address, 0)
+    }
+
+    fn read_register_byte(&self, address: u8, index: usize) -> u8 {
+        let address_bytes = match address {
+            registers::RxAddrP0::<5>::ADDRESS => Some(&self.rx_addr_p0),
+            registers::RxAddrP1::<5>::ADDRESS => Some(&self.rx_addr_p1),
+            registers::TxAddr::<5>::ADDRESS => Some(&self.tx_addr),
+            _ => None,
+        };
+        if let Some(bytes) = address_bytes {
+            return bytes.get(index).copied().unwrap_or(0);
+        }
+        if index > 0 {
+            return 0;
+        }
+        match address {
+            registers::Status::ADDRESS => self.status().into_bits(),
+            registers::FifoStatus::ADDRESS => self.fifo_status().into_bits(),
+            _ => self.registers.get(address as usize).copied().unwrap_or(0),
+        }
+    }
+
+    fn write_register(&mut self, address: u8, bytes: &[u8]) {
+        let address_bytes = match address {
+            registers::RxAddrP0::<5>::ADDRESS => Some(&mut self.rx_addr_p0),
+            registers::RxAddrP1::<5>::ADDRESS => Some(&mut self.rx_addr_p1),
+            registers::TxAddr::<5>::ADDRESS => Some(&mut self.tx_addr),
+            _ => None,
+        };
+        if let Some(address_bytes) = address_bytes {
+            address_bytes[..bytes.len()].copy_from_slice(bytes);
+            return;
+        }
+        let Some(&value) = bytes.first() else {
+            return;
+        };
+        match address {
+            registers::Status::ADDRESS => {
+                self.registers[address as usize] &= !(value & STATUS_IRQ_MASK);
+            }
+            registers::ObserveTx::ADDRESS
+            | registers::Rpd::ADDRESS
+            | registers::FifoStatus::ADDRESS => {}
+            registers::RfCh::ADDRESS => {
+                self.registers[address as usize] = value;
+                // Writing RF_CH resets the lost packet count
+                self.registers[registers::ObserveTx::ADDRESS as usize] &= 0x0F;
+            }
+            _ => {
+                if let Some(register) = self.registers.get_mut(address as usize) {
+                    *register = value;
+                }
+            }
+        }
+    }
+
+    fn set_irq_flags(&mut self, flags: u8) {
+        self.registers[registers::Status::ADDRESS as usize] |= flags & STATUS_IRQ_MASK;
+    }
+
+    fn irq(&self) -> bool {
+        let status = self.registers[registers::Status::ADDRESS as usize];
+        // CONFIG mask bits are in the same positions as the STATUS flags
+        let mask = self.registers[registers::Config::ADDRESS as usize];
+        status & !mask & STATUS_IRQ_MASK != 0
+    }
+
+    fn begin(&mut self) {
+        self.decode = Decode::Word;
+    }
+
+    /// Exchange one byte. Returns the MISO byte.
+    fn exchange(&mut self, mosi: u8) -> u8 {
+        match &mut self.decode {
+            Decode::Word => {
+                self.decode = match mosi {
+                    w if w & 0b1110_0000 == commands::RRegister::<registers::Config>::WORD => {
+                        Decode::ReadRegister {
+                            address: w & 0x1F,
+                            index: 0,
+                        }
+                    }
+                    w if w & 0b1110_0000 == commands::WRegister::<registers::Config>::WORD => {
+                        Decode::WriteRegister {
+                            address: w & 0x1F,
+                            bytes: [0; MAX_ADDRESS_WIDTH],
+                            len: 0,
+                        }
+                    }
+                    commands::RRxPayload::<1>::WORD => Decode::ReadRxPayload { index: 0 },
+                    commands::RRxPlWid::WORD => Decode::ReadRxPayloadWidth,
+                    commands::WTxPayload::<1>::WORD => Decode::WritePayload(TxEntry {
+                        payload: Payload::new(&[]),
+                        no_ack: false,
+                        ack_pipe: None,
+                    }),
+                    commands::WTxPayloadNoack::<1>::WORD => Decode::WritePayload(TxEntry {
+                        payload: Payload::new(&[]),
+                        no_ack: true,
+                        ack_pipe: None,
+                    }),
+                    w if w & 0b1111_1000 == commands::WAckPayload::<1>::WORD => {
+                        match fields::Pipe::try_from_bits(w & 0b111) {
+                            Ok(pipe) => Decode::WritePayload(TxEntry {
+                                payload: Payload::new(&[]),
+                                no_ack: false,
+                                ack_pipe: Some(pipe),
+                            }),
+                            Err(_) => Decode::Ignore,
+                        }
+                    }
+                    commands::FlushTx::WORD
+                    | commands::FlushRx::WORD
+                    | commands::ReuseTxPl::WORD
+                    | commands::Nop::WORD => Decode::Single(mosi),
+                    _ => Decode::Ignore,
+                };
+                self.status().into_bits()
+            }
+            Decode::ReadRegister { address, index } => {
+                let (address, i) = (*address, *index);
+                *index += 1;
+                self.read_register_byte(address, i)
+            }
+            Decode::WriteRegister { bytes, len, .. } => {
+                if *len < MAX_ADDRESS_WIDTH {
+                    bytes[*len] = mosi;
+                    *len += 1;
+                }
+                0
+            }
+            Decode::ReadRxPayload { index } => {
+                let i = *index;
+                *index += 1;
+                match self.rx_fifo.peek() {
+                    Some(entry) => entry.payload.as_slice().get(i).copied().unwrap_or(0),
+                    None => 0,
+                }
+            }
+            Decode::ReadRxPayloadWidth => {
+                self.decode = Decode::Ignore;
+                self.rx_fifo.peek().map_or(0, |entry| entry.payload.len)
+            }
+            Decode::WritePayload(entry) => {
+                entry.payload.push(mosi);
+                0
+            }
+            Decode::Single(_) | Decode::Ignore => 0,
+        }
+    }
+
+    /// End the transaction and execute the decoded command.
+    fn end(&mut self) {
+        match core::mem::replace(&mut self.decode, Decode::Word) {
+            Decode::WriteRegister {
+                address,
+                bytes,
+                len,
+            } => self.write_register(address, &bytes[..len]),
+            Decode::ReadRxPayload { index } if index > 0 => {
+                self.rx_fifo.pop();
+            }
+            Decode::WritePayload(entry) if !entry.payload.is_empty() => {
+                let pushed = self.tx_fifo.push(entry);
+                // A new TX payload ends REUSE_TX_PL, an ACK payload doesn't
+                if pushed && entry.ack_pipe.is_none() {
+                    self.tx_reuse = false;
+                }
+            }
+            Decode::Single(commands::FlushTx::WORD) => {
+                self.tx_fifo.clear();
+                self.tx_reuse = false;
+                self.tx_head_sent = false;
+            }
+            Decode::Single(commands::FlushRx::WORD) => self.rx_fifo.clear(),
+            Decode::Single(commands::ReuseTxPl::WORD) => self.tx_reuse = true,
+            _ => {}
+        }
+    }
+}
+
+/// Simulated nRF24L01+.
+///
+/// The state is kept in a [`RefCell`], so [`Spi`], [`Ce`] and [`Irq`] handles can be used
+/// at the same time while the chip is inspected.
+#[derive(Debug)]
+pub struct Nrf24l01 {
+    chip: RefCell<Chip>,
+}
+
+impl Nrf24l01 {
+    /// Create a chip with all registers at their reset values and empty FIFOs.
+    pub fn new() -> Self {
+        Self {
+            chip: RefCell::new(Chip::new()),
+        }
+    }
+
+    /// SPI device handle.
+    pub fn spi(&self) -> Spi<'_> {
+        Spi(self)
+    }
+
+    /// CE pin handle.
+    pub fn ce(&self) -> Ce<'_> {
+        Ce(self)
+    }
+
+    /// Active-low IRQ pin handle.
+    pub fn irq(&self) -> Irq<'_> {
+        Irq(self)
+    }
+
+    /// Current value of a single-byte register.
+    pub fn register<R: Register>(&self) -> R {
+        self.chip.borrow().register()
+    }
+
+    /// Current value of an address register.
+    pub fn address_register<R: AddressRegister<N>, const N: usize>(&self) -> R {
+        let chip = self.chip.borrow();
+        let bytes = match R::ADDRESS {
+            registers::RxAddrP0::<5>::ADDRESS => &chip.rx_addr_p0,
+            registers::RxAddrP1::<5>::ADDRESS => &chip.rx_addr_p1,
+            _ => &chip.tx_addr,
+        };
+        let mut address = [0; N];
+        address.copy_from_slice(&bytes[..N]);
+        R::from_bytes(address)
+    }
+
+    /// State of the CE pin.
+    pub fn ce_high(&self) -> bool {
+        self.chip.borrow().ce
+    }
+
+    /// Whether the IRQ pin is asserted (low).
+    pub fn irq_asserted(&self) -> bool {
+        self.chip.borrow().irq()
+    }
+
+    /// Number of entries in the TX FIFO.
+    pub fn tx_fifo_len(&self) -> usize {
+        self.chip.borrow().tx_fifo.len
+    }
+
+    /// Number of entries in the RX FIFO.
+    pub fn rx_fifo_len(&self) -> usize {
+        self.chip.borrow().rx_fifo.len
+    }
+
+    /// Push a received payload into the RX FIFO and assert `rx_dr`.
+    /// Returns `false` if the RX FIFO is full and the payload is dropped.
+    pub fn receive(&self, pipe: fields::Pipe, payload: &[u8]) -> bool {
+        let mut chip = self.chip.borrow_mut();
+        let entry = RxEntry {
+            pipe,
+            payload: Payload::new(payload),
+        };
+        let received = chip.rx_fifo.push(entry);
+        if received {
+            chip.set_irq_flags(registers::Status::new().with_rx_dr(true).into_bits());
+        }
+        received
+    }
+
+    /// Pop the TX FIFO head and assert `tx_ds`, as if the packet was transmitted.
+    /// If `REUSE_TX_PL` is active, the head is kept.
+    pub fn transmit(&self) -> Option<TxEntry> {
+        let mut chip = self.chip.borrow_mut();
+        let entry = if chip.tx_reuse {
+            chip.tx_fifo.peek()
+        } else {
+            chip.tx_head_sent = false;
+            chip.tx_fifo.pop()
+        }?;
+        chip.set_irq_flags(registers::Status::new().with_tx_ds(true).into_bits());
+        Some(entry)
+    }
+}
+
+impl Default for Nrf24l01 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// SPI device handle of a simulated [`Nrf24l01`].
+///
+/// Each [`transaction`](SpiDevice::transaction) is decoded as one command, with MOSI bytes
+/// concatenated across operations. `Read` operations shift out `0xFF`.
+#[derive(Copy, Clone, Debug)]
+pub struct Spi<'a>(&'a Nrf24l01);
+
+impl spi::ErrorType for Spi<'_> {
+    type Error = Infallible;
+}
+
+impl SpiDevice for Spi<'_> {
+    fn transaction(&mut self, operations: &mut [Operation<'_, u8>]) -> Result<(), Infallible> {
+        let mut chip = self.0.chip.borrow_mut();
+        chip.begin();
+        for operation in operations {
+            match operation {
+                Operation::Read(read) => {
+                    for byte in read.iter_mut() {
+                        *byte = chip.exchange(0xFF);
+                    }
+                }
+                Operation::Write(write) => {
+                    for &byte in write.iter() {
+                        chip.exchange(byte);
+                    }
+                }
+                Operation::Transfer(read, write) => {
+                    for i in 0..read.len().max(write.len()) {
+                        let miso = chip.exchange(write.get(i).copied().unwrap_or(0xFF));
+                        if let Some(byte) = read.get_mut(i) {
+                            *byte = miso;
+                        }
+                    }
+                }
+                Operation::TransferInPlace(words) => {
+                    for byte in words.iter_mut() {
+                        *byte = chip.exchange(*byte);
+                    }
+                }
+                Operation::DelayNs(_) => {}
+            }
+        }
+        chip.end();
+        Ok(())
+    }
+}
+
+/// CE pin handle of a simulated [`Nrf24l01`].
+#[derive(Copy, Clone, Debug)]
+pub struct Ce<'a>(&'a Nrf24l01);
+
+impl digital::ErrorType for Ce<'_> {
+    type Error = Infallible;
+}
+
+impl OutputPin for Ce<'_> {
+    fn set_low(&mut self) -> Result<(), Infallible> {
+        self.0.chip.borrow_mut().ce = false;
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Infallible> {
+        let mut chip = self.0.chip.borrow_mut();
+        chip.ce = true;
+        chip.ce_pulse = true;
+        Ok(())
+    }
+}
+
+/// Active-low IRQ pin handle of a simulated [`Nrf24l01`].
+#[derive(Copy, Clone, Debug)]
+pub struct Irq<'a>(&'a Nrf24l01);
+
+impl digital::ErrorType for Irq<'_> {
+    type Error = Infallible;
+}
+
+impl InputPin for Irq<'_> {
+    fn is_high(&mut self) -> Result<bool, Infallible> {
+        Ok(!self.0.irq_asserted())
+    }
+
+    fn is_low(&mut self) -> Result<bool, Infallible> {
+        Ok(self.0.irq_asserted())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spi::SpiDeviceExt;
+
+    #[test]
+    fn test_reset_values() {
+        let chip = Nrf24l01::new();
+        let mut spi = chip.spi();
+
+        let response = spi
+            .execute(commands::RRegister::<registers::Config>::new())
+            .unwrap();
+        assert_eq!(
+            response.register.into_bits(),
+            registers::Config::new().into_bits()
+        );
+        assert_eq!(response.status.rx_p_no(), fields::RxPipeNo::RxFifoEmpty);
+        let response = spi
+            .execute(commands::RRegister::<registers::SetupRetr>::new())
+            .unwrap();
+        assert_eq!(response.register.arc(), 3);
+        let response = spi
+            .execute(commands::RRegister::<registers::RxAddrP1<5>>::new())
+            .unwrap();
+        assert_eq!(response.register.rx_addr_p1(), 0xC2C2C2C2C2);
+        let response = spi
+            .execute(commands::RRegister::<registers::FifoStatus>::new())
+            .unwrap();
+        assert!(response.register.tx_empty());
+        assert!(response.register.rx_empty());
+    }
+
+    #[test]
+    fn test_write_registers() {
+        let chip = Nrf24l01::new();
+        let mut spi = chip.spi();
+
+        let rf_setup = registers::RfSetup::new().with_rf_pwr(fields::RfPower::Neg12Dbm);
+        spi.execute(commands::WRegister(rf_setup)).unwrap();
+        let tx_addr = registers::TxAddr::<3>::new().with_tx_addr(0xA1B2C3);
+        spi.execute(commands::WRegister(tx_addr)).unwrap();
+        // Read-only registers are not written
+        spi.transfer_in_place(&mut [0b0010_0000 | registers::ObserveTx::ADDRESS, 0xFF])
+            .unwrap();
+
+        assert_eq!(
+            chip.register::<registers::RfSetup>().rf_pwr(),
+            fields::RfPower::Neg12Dbm
+        );
+        assert_eq!(
+            chip.address_register::<registers::TxAddr<3>, 3>().tx_addr(),
+            0xA1B2C3
+        );
+        assert_eq!(chip.register::<registers::ObserveTx>().into_bits(), 0);
+    }
+
+    #[test]
+    fn test_tx_fifo() {
+        let chip = Nrf24l01::new();
+        let mut spi = chip.spi();
+
+        spi.execute(commands::WTxPayload([1, 2])).unwrap();
+        spi.execute(commands::WTxPayloadNoack([3])).unwrap();
+        spi.execute(commands::WAckPayload {
+            pipe: fields::Pipe::P2,
+            payload: [4],
+        })
+        .unwrap();
+        // Dropped when full
+        spi.execute(commands::WTxPayload([5])).unwrap();
+        assert_eq!(chip.tx_fifo_len(), 3);
+        let status = spi.execute(commands::Nop()).unwrap();
+        assert!(status.tx_full());
+
+        let entry = chip.transmit().unwrap();
+        assert_eq!(entry.payload.as_slice(), &[1, 2]);
+        assert!(!entry.no_ack);
+        assert!(chip.transmit().unwrap().no_ack);
+        assert_eq!(chip.transmit().unwrap().ack_pipe, Some(fields::Pipe::P2));
+        assert!(chip.transmit().is_none());
+
+        let status = spi.execute(commands::Nop()).unwrap();
+        assert!(status.tx_ds());
+        assert!(!status.tx_full());
+        assert!(chip.irq_asserted());
+
+        // Clear TX_DS
+        spi.execute(commands::WRegister(
+            registers::Status::new().with_tx_ds(true),
+        ))
+        .unwrap();
+        assert!(!chip.irq_asserted());
+
+        spi.execute(commands::WTxPayload([6])).unwrap();
+        spi.execute(commands::FlushTx()).unwrap();
+        assert_eq!(chip.tx_fifo_len(), 0);
+    }
+
+    #[test]
+    fn test_reuse_tx_payload() {
+        let chip = Nrf24l01::new();
+        let mut spi = chip.spi();
+
+        spi.execute(commands::WTxPayload([7])).unwrap();
+        spi.execute(commands::ReuseTxPl()).unwrap();
+        let response = spi
+            .execute(commands::RRegister::<registers::FifoStatus>::new())
+            .unwrap();
+        assert!(response.register.tx_reuse());
+        assert_eq!(chip.transmit().unwrap().payload.as_slice(), &[7]);
+        assert_eq!(chip.transmit().unwrap().payload.as_slice(), &[7]);
+        assert_eq!(chip.tx_fifo_len(), 1);
+    }
+
+    #[test]
+    fn test_rx_fifo() {
+        let chip = Nrf24l01::new();
+        let mut spi = chip.spi();
+
+        assert!(chip.receive(fields::Pipe::P1, &[1, 2, 3]));
+        assert!(chip.receive(fields::Pipe::P4, &[4]));
+        assert!(chip.receive(fields::Pipe::P0, &[5]));
+        assert!(!chip.receive(fields::Pipe::P0, &[6]));
+
+        let response = spi.execute(commands::RRxPlWid()).unwrap();
+        assert!(response.status.rx_dr());
+        assert_eq!(response.status.rx_p_no(), fields::RxPipeNo::Pipe1);
+        assert_eq!(response.width, 3);
+        let response = spi.execute(commands::RRxPayload::<3>()).unwrap();
+        assert_eq!(response.payload, [1, 2, 3]);
+
+        let response = spi.execute(commands::RRxPayload::<1>()).unwrap();
+        assert_eq!(response.status.rx_p_no(), fields::RxPipeNo::Pipe4);
+        assert_eq!(response.payload, [4]);
+
+        spi.execute(commands::FlushRx()).unwrap();
+        let status = spi.execute(commands::Nop()).unwrap();
+        assert_eq!(status.rx_p_no(), fields::RxPipeNo::RxFifoEmpty);
+        assert_eq!(chip.rx_fifo_len(), 0);
+    }
+
+    #[test]
+    fn test_irq_mask() {
+        let chip = Nrf24l01::new();
+        let mut spi = chip.spi();
+        let mut irq = chip.irq();
+
+        let config = registers::Config::new().with_mask_rx_dr(true);
+        spi.execute(commands::WRegister(config)).unwrap();
+        chip.receive(fields::Pipe::P0, &[1]);
+        assert!(irq.is_high().unwrap());
+
+        spi.execute(commands::WRegister(registers::Config::new()))
+            .unwrap();
+        assert!(irq.is_low().unwrap());
+    }
+
+    #[cfg(feature = "driver")]
+    #[test]
+    fn test_driver() {
+        use crate::driver;
+        use embedded_hal_mock::eh1::delay::NoopDelay;
+
+        let chip = Nrf24l01::new();
+        let radio = driver::Nrf24l01::new(
+            chip.spi(),
+            chip.ce(),
+            NoopDelay::new(),
+            registers::Config::new(),
+        )
+        .unwrap();
+        let radio = radio.power_up().map_err(|(_, e)| e).unwrap();
+        assert!(chip.register::<registers::Config>().pwr_up());
+
+        let mut radio = radio.start_rx().map_err(|(_, e)| e).unwrap();
+        assert!(chip.ce_high());
+        assert!(chip.register::<registers::Config>().prim_rx());
+
+        chip.receive(fields::Pipe::P2, &[9, 8]);
+        assert_eq!(radio.rx_payload_width().unwrap(), 2);
+        assert_eq!(radio.read_rx_payload::<2>().unwrap().payload, [9, 8]);
+
+        radio.stop_rx().map_err(|(_, e)| e).unwrap();
+        assert!(!chip.ce_high());
+    }
+}
@@ -0,0 +1,703 @@
+//! Over-the-air medium connecting simulated chips with Enhanced ShockBurst semantics.
+use super::{Chip, Nrf24l01, Payload, RxEntry, TxEntry};
+use crate::registers::{self, Register};
+use crate::{airtime, fields};
+
+/// Settings that must match between a transmitter and a receiver.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct Phy {
+    channel: u8,
+    /// `RF_SETUP` data rate bits `RF_DR_LOW` and `RF_DR_HIGH`.
+    data_rate: u8,
+    address_width: usize,
+    /// CRC length in bytes.
+    crc: u8,
+}
+
+/// A packet on air.
+#[derive(Copy, Clone, Debug)]
+struct Packet {
+    phy: Phy,
+    address: [u8; 5],
+    pid: u8,
+    no_ack: bool,
+    payload: Payload,
+}
+
+/// An ACK packet on air.
+#[derive(Copy, Clone, Debug)]
+struct Ack {
+    from: usize,
+    payload: Option<Payload>,
+}
+
+impl Chip {
+    fn config(&self) -> registers::Config {
+        self.register()
+    }
+
+    fn feature(&self) -> registers::Feature {
+        self.register()
+    }
+
+    fn phy(&self) -> Option<Phy> {
+        let address_width = match self.register::<registers::SetupAw>().aw() {
+            fields::AddressWidth::Illegal => return None,
+            fields::AddressWidth::ThreeByte => 3,
+            fields::AddressWidth::FourByte => 4,
+            fields::AddressWidth::FiveByte => 5,
+        };
+        let config = self.config();
+        // CRC is forced on when any pipe has auto-ACK enabled
+        let crc = if config.en_crc() || self.register::<registers::EnAa>().into_bits() != 0 {
+            match config.crco() {
+                fields::Crco::OneByte => 1,
+                fields::Crco::TwoByte => 2,
+            }
+        } else {
+            0
+        };
+        Some(Phy {
+            channel: self.register::<registers::RfCh>().rf_ch(),
+            data_rate: self.register::<registers::RfSetup>().into_bits() & 0b0010_1000,
+            address_width,
+            crc,
+        })
+    }
+
+    /// Full address of a data pipe. Pipes 2-5 share the upper bytes of pipe 1.
+    fn pipe_address(&self, pipe: fields::Pipe) -> [u8; 5] {
+        match pipe {
+            fields::Pipe::P0 => self.rx_addr_p0,
+            fields::Pipe::P1 => self.rx_addr_p1,
+            _ => {
+                let mut address = self.rx_addr_p1;
+                address[0] =
+                    self.read_register(registers::RxAddrP2::ADDRESS + pipe.into_bits() - 2);
+                address
+            }
+        }
+    }
+
+    fn pipe_bit(&self, address: u8, pipe: fields::Pipe) -> bool {
+        self.read_register(address) >> pipe.into_bits() & 1 == 1
+    }
+
+    fn auto_ack(&self, pipe: fields::Pipe) -> bool {
+        self.pipe_bit(registers::EnAa::ADDRESS, pipe)
+    }
+
+    fn dynamic_payload(&self, pipe: fields::Pipe) -> bool {
+        self.feature().en_dpl() && self.pipe_bit(registers::Dynpd::ADDRESS, pipe)
+    }
+
+    /// Primary TX, powered up, CE high or pulsed, and not blocked by `max_rt`.
+    fn transmitting(&self) -> bool {
+        let config = self.config();
+        config.pwr_up()
+            && !config.prim_rx()
+            && (self.ce || self.ce_pulse)
+            && !self.status().max_rt()
+            && !self.tx_fifo.is_empty()
+    }
+
+    /// Primary RX, powered up and CE high.
+    fn listening(&self) -> bool {
+        let config = self.config();
+        config.pwr_up() && config.prim_rx() && self.ce
+    }
+
+    /// Pipe that accepts `packet`, if any.
+    fn match_pipe(&self, packet: &Packet) -> Option<fields::Pipe> {
+        if self.phy() != Some(packet.phy) {
+            return None;
+        }
+        let width = packet.phy.address_width;
+        fields::Pipe::ALL.into_iter().find(|&pipe| {
+            self.pipe_bit(registers::EnRxaddr::ADDRESS, pipe)
+                && self.pipe_address(pipe)[..width] == packet.address[..width]
+                && (self.dynamic_payload(pipe)
+                    || self.read_register(registers::RxPwP0::ADDRESS + pipe.into_bits()) as usize
+                        == packet.payload.len())
+        })
+    }
+
+    /// Receive `packet` on `pipe`. Returns whether an ACK is sent.
+    fn receive_packet(&mut self, pipe: fields::Pipe, packet: &Packet) -> bool {
+        let last = &mut self.rx_last[pipe.into_bits() as usize];
+        // Retransmitted packets are acknowledged but discarded
+        if *last != Some((packet.pid, packet.payload)) {
+            let entry = RxEntry {
+                pipe,
+                payload: packet.payload,
+            };
+            if !self.rx_fifo.push(entry) {
+                return false;
+            }
+            self.rx_last[pipe.into_bits() as usize] = Some((packet.pid, packet.payload));
+            self.set_irq_flags(registers::Status::new().with_rx_dr(true).into_bits());
+        }
+        !packet.no_ack && self.auto_ack(pipe)
+    }
+
+    /// Pop the first ACK payload queued for `pipe`.
+    fn pop_ack_payload(&mut self, pipe: fields::Pipe) -> Option<Payload> {
+        if !self.feature().en_ack_pay() || !self.dynamic_payload(pipe) {
+            return None;
+        }
+        let mut payload = None;
+        let mut kept = super::Fifo::<TxEntry>::new();
+        while let Some(entry) = self.tx_fifo.pop() {
+            if payload.is_none() && entry.ack_pipe == Some(pipe) {
+                payload = Some(entry.payload);
+            } else {
+                kept.push(entry);
+            }
+        }
+        self.tx_fifo = kept;
+        payload
+    }
+
+    fn set_observe_tx(&mut self, plos_cnt: u8, arc_cnt: u8) {
+        self.registers[registers::ObserveTx::ADDRESS as usize] = plos_cnt << 4 | arc_cnt;
+    }
+}
+
+/// Virtual medium connecting simulated chips over the air.
+///
+/// Each [`step`](Medium::step), every chip in primary TX mode with CE high (or pulsed) and a
+/// non-empty TX FIFO transmits its top packet. A packet is delivered to chips in primary RX
+/// mode with a matching channel, data rate, address width, CRC length and pipe address.
+/// Auto-ACK, ACK payloads, retransmits per [`SETUP_RETR`][registers::SetupRetr], packet ID
+/// deduplication and the [`OBSERVE_TX`][registers::ObserveTx] counters follow the chip.
+///
+/// Links between chips are up by default and can be cut with [`set_link`](Medium::set_link)
+/// to simulate lost packets and ACKs.
+///
+/// ## Example
+/// ```rust
+/// use nrf24l01_commands::{commands, registers, sim, spi::SpiDeviceExt};
+///
+/// let ptx = sim::Nrf24l01::new();
+/// let prx = sim::Nrf24l01::new();
+/// let medium = sim::Medium::new([&ptx, &prx]);
+///
+/// // Power up both chips, PRX with a 2 byte static payload width on pipe 0
+/// let config = registers::Config::new().with_pwr_up(true);
+/// ptx.spi().execute(commands::WRegister(config)).unwrap();
+/// prx.spi().execute(commands::WRegister(config.with_prim_rx(true))).unwrap();
+/// prx.spi().execute(commands::WRegister(registers::RxPwP0::new().with_rx_pw_p0(2))).unwrap();
+/// embedded_hal::digital::OutputPin::set_high(&mut prx.ce()).unwrap();
+///
+/// // Send a packet
+/// ptx.spi().execute(commands::WTxPayload([1, 2])).unwrap();
+/// embedded_hal::digital::OutputPin::set_high(&mut ptx.ce()).unwrap();
+/// assert_eq!(medium.step(), 1);
+///
+/// assert!(ptx.spi().execute(commands::Nop()).unwrap().tx_ds());
+/// let response = prx.spi().execute(commands::RRxPayload::<2>()).unwrap();
+/// assert_eq!(response.payload, [1, 2]);
+/// ```
+#[derive(Debug)]
+pub struct Medium<'a, const N: usize> {
+    nodes: [&'a Nrf24l01; N],
+    links: core::cell::Cell<[[bool; N]; N]>,
+}
+
+impl<'a, const N: usize> Medium<'a, N> {
+    /// Connect `nodes`. Node indices are positions in `nodes`.
+    pub fn new(nodes: [&'a Nrf24l01; N]) -> Self {
+        Self {
+            nodes,
+            links: core::cell::Cell::new([[true; N]; N]),
+        }
+    }
+
+    /// Set whether packets sent by node `from` reach node `to`.
+    pub fn set_link(&self, from: usize, to: usize, up: bool) {
+        let mut links = self.links.get();
+        links[from][to] = up;
+        self.links.set(links);
+    }
+
+    /// Transmit the top packet of every transmitting node, including retransmits.
+    /// Returns the number of packets transmitted.
+    pub fn step(&self) -> usize {
+        let mut transmitted = 0;
+        for from in 0..N {
+            if self.transmit(from) {
+                transmitted += 1;
+            }
+        }
+        transmitted
+    }
+
+    fn transmit(&self, from: usize) -> bool {
+        let (packet, expect_ack, retransmits, tx_reuse) = {
+            let mut chip = self.nodes[from].chip.borrow_mut();
+            if !chip.transmitting() {
+                return false;
+            }
+            let Some(phy) = chip.phy() else {
+                return false;
+            };
+            let Some(entry) = chip.tx_fifo.peek() else {
+                return false;
+            };
+            // The PID only advances when a new payload reaches the FIFO head
+            if !chip.tx_head_sent {
+                chip.pid = (chip.pid + 1) % 4;
+                chip.tx_head_sent = true;
+            }
+            let no_ack = entry.no_ack && chip.feature().en_dyn_ack();
+            let packet = Packet {
+                phy,
+                address: chip.tx_addr,
+                pid: chip.pid,
+                no_ack,
+                payload: entry.payload,
+            };
+            let retransmits = chip.register::<registers::SetupRetr>().arc();
+            (
+                packet,
+                !no_ack && chip.auto_ack(fields::Pipe::P0),
+                retransmits,
+                chip.tx_reuse,
+            )
+        };
+
+        let mut attempts = 0;
+        let acked = loop {
+            let ack = self.deliver(from, &packet);
+            if !expect_ack {
+                break true;
+            }
+            if let Some(ack) = ack
+                && self.receive_ack(from, &packet, ack)
+            {
+                break true;
+            }
+            if attempts == retransmits {
+                break false;
+            }
+            attempts += 1;
+        };
+
+        let mut chip = self.nodes[from].chip.borrow_mut();
+        let plos_cnt = chip.register::<registers::ObserveTx>().plos_cnt();
+        chip.ce_pulse = false;
+        if acked {
+            if !tx_reuse {
+                chip.tx_fifo.pop();
+                chip.tx_head_sent = false;
+            }
+            chip.set_observe_tx(plos_cnt, attempts);
+            chip.set_irq_flags(registers::Status::new().with_tx_ds(true).into_bits());
+        } else {
+            chip.set_observe_tx((plos_cnt + 1).min(15), attempts);
+            chip.set_irq_flags(registers::Status::new().with_max_rt(true).into_bits());
+        }
+        true
+    }
+
+    /// Deliver a packet from node `from` to every receiving node. Returns the first ACK.
+    fn deliver(&self, from: usize, packet: &Packet) -> Option<Ack> {
+        let links = self.links.get();
+        let mut first_ack = None;
+        for to in (0..N).filter(|&to| to != from && links[from][to]) {
+            let mut chip = self.nodes[to].chip.borrow_mut();
+            if !chip.listening() {
+                continue;
+            }
+            let Some(pipe) = chip.match_pipe(packet) else {
+                continue;
+            };
+            if chip.receive_packet(pipe, packet) && first_ack.is_none() {
+                let payload = if links[to][from] {
+                    chip.pop_ack_payload(pipe)
+                } else {
+                    None
+                };
+                first_ack = Some(Ack { from: to, payload });
+            }
+        }
+        first_ack
+    }
+
+    /// Receive an ACK on pipe 0 of node `to`. Returns whether the ACK was received.
+    ///
+    /// An ACK payload that doesn't fit in the auto retransmit delay is missed, and one that
+    /// arrives with `EN_DPL` or `EN_ACK_PAY` off is discarded.
+    fn receive_ack(&self, to: usize, packet: &Packet, ack: Ack) -> bool {
+        if !self.links.get()[ack.from][to] {
+            return false;
+        }
+        let mut chip = self.nodes[to].chip.borrow_mut();
+        let width = packet.phy.address_width;
+        if chip.rx_addr_p0[..width] != packet.address[..width] {
+            return false;
+        }
+        if let Some(payload) = ack.payload {
+            let ard = chip.register::<registers::SetupRetr>().ard();
+            let min_ard = airtime::min_ard(chip.register(), payload.len() as u8);
+            if airtime::ard_us(ard) < airtime::ard_us(min_ard) {
+                return false;
+            }
+            let feature = chip.feature();
+            if !feature.en_dpl() || !feature.en_ack_pay() {
+                return true;
+            }
+            let entry = RxEntry {
+                pipe: fields::Pipe::P0,
+                payload,
+            };
+            if chip.rx_fifo.push(entry) {
+                chip.set_irq_flags(registers::Status::new().with_rx_dr(true).into_bits());
+            }
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands;
+    use crate::spi::SpiDeviceExt;
+    use embedded_hal::digital::OutputPin;
+
+    fn power_up(chip: &Nrf24l01, prim_rx: bool) {
+        let mut spi = chip.spi();
+        let config = registers::Config::new()
+            .with_pwr_up(true)
+            .with_prim_rx(prim_rx);
+        spi.execute(commands::WRegister(config)).unwrap();
+        if prim_rx {
+            chip.ce().set_high().unwrap();
+        }
+    }
+
+    fn enable_dynamic_payloads(chip: &Nrf24l01) {
+        let mut spi = chip.spi();
+        let feature = registers::Feature::new()
+            .with_en_dpl(true)
+            .with_en_ack_pay(true)
+            .with_en_dyn_ack(true);
+        spi.execute(commands::WRegister(feature)).unwrap();
+        spi.execute(commands::WRegister(registers::Dynpd::from_bits(
+            0b0011_1111,
+        )))
+        .unwrap();
+    }
+
+    fn send(chip: &Nrf24l01, payload: &[u8; 2]) {
+        chip.spi().execute(commands::WTxPayload(*payload)).unwrap();
+        chip.ce().set_high().unwrap();
+        chip.ce().set_low().unwrap();
+    }
+
+    fn read_status(chip: &Nrf24l01) -> registers::Status {
+        chip.spi().execute(commands::Nop()).unwrap()
+    }
+
+    #[test]
+    fn test_address_matching() {
+        let ptx = Nrf24l01::new();
+        let prx = Nrf24l01::new();
+        let other = Nrf24l01::new();
+        let medium = Medium::new([&ptx, &prx, &other]);
+        power_up(&ptx, false);
+        power_up(&prx, true);
+        power_up(&other, true);
+        for chip in [&prx, &other] {
+            let mut spi = chip.spi();
+            spi.execute(commands::WRegister(registers::EnRxaddr::from_bits(
+                0b0000_0110,
+            )))
+            .unwrap();
+            spi.execute(commands::WRegister(
+                registers::RxPwP2::new().with_rx_pw_p2(2),
+            ))
+            .unwrap();
+        }
+        // Other chip listens on a different channel
+        other
+            .spi()
+            .execute(commands::WRegister(registers::RfCh::new().with_rf_ch(40)))
+            .unwrap();
+
+        // Address of pipe 2 with the upper bytes of pipe 1
+        let tx_addr = registers::TxAddr::<5>::new().with_tx_addr(0xC2C2C2C2C3);
+        let mut spi = ptx.spi();
+        spi.execute(commands::WRegister(tx_addr)).unwrap();
+        spi.execute(commands::WRegister(registers::RxAddrP0::<5>::from_bits(
+            0xC2C2C2C2C3,
+        )))
+        .unwrap();
+
+        send(&ptx, &[1, 2]);
+        assert_eq!(medium.step(), 1);
+        assert!(read_status(&ptx).tx_ds());
+        let response = prx.spi().execute(commands::RRxPayload::<2>()).unwrap();
+        assert_eq!(response.status.rx_p_no(), fields::RxPipeNo::Pipe2);
+        assert_eq!(response.payload, [1, 2]);
+        assert_eq!(other.rx_fifo_len(), 0);
+
+        // Nothing left to send
+        assert_eq!(medium.step(), 0);
+    }
+
+    #[test]
+    fn test_retransmits() {
+        let ptx = Nrf24l01::new();
+        let prx = Nrf24l01::new();
+        let medium = Medium::new([&ptx, &prx]);
+        power_up(&ptx, false);
+        power_up(&prx, true);
+        prx.spi()
+            .execute(commands::WRegister(
+                registers::RxPwP0::new().with_rx_pw_p0(2),
+            ))
+            .unwrap();
+
+        // PRX is out of range
+        medium.set_link(0, 1, false);
+        send(&ptx, &[1, 2]);
+        assert_eq!(medium.step(), 1);
+        let status = read_status(&ptx);
+        assert!(status.max_rt());
+        assert!(!status.tx_ds());
+        let observe_tx = ptx.register::<registers::ObserveTx>();
+        assert_eq!(observe_tx.arc_cnt(), 3);
+        assert_eq!(observe_tx.plos_cnt(), 1);
+        assert_eq!(ptx.tx_fifo_len(), 1);
+
+        // Blocked until MAX_RT is cleared
+        medium.set_link(0, 1, true);
+        ptx.ce().set_high().unwrap();
+        assert_eq!(medium.step(), 0);
+        ptx.spi()
+            .execute(commands::WRegister(
+                registers::Status::new().with_max_rt(true),
+            ))
+            .unwrap();
+        assert_eq!(medium.step(), 1);
+        assert!(read_status(&ptx).tx_ds());
+        assert_eq!(ptx.register::<registers::ObserveTx>().arc_cnt(), 0);
+        assert_eq!(prx.rx_fifo_len(), 1);
+
+        // Writing RF_CH resets the lost packet count
+        ptx.spi()
+            .execute(commands::WRegister(registers::RfCh::new()))
+            .unwrap();
+        assert_eq!(ptx.register::<registers::ObserveTx>().plos_cnt(), 0);
+    }
+
+    #[test]
+    fn test_lost_ack_deduplication() {
+        let ptx = Nrf24l01::new();
+        let prx = Nrf24l01::new();
+        let medium = Medium::new([&ptx, &prx]);
+        power_up(&ptx, false);
+        power_up(&prx, true);
+        prx.spi()
+            .execute(commands::WRegister(
+                registers::RxPwP0::new().with_rx_pw_p0(2),
+            ))
+            .unwrap();
+
+        // ACKs are lost, every retransmit reaches the PRX
+        medium.set_link(1, 0, false);
+        send(&ptx, &[1, 2]);
+        medium.step();
+        assert!(read_status(&ptx).max_rt());
+        assert_eq!(prx.rx_fifo_len(), 1);
+
+        // Same PID and payload after clearing MAX_RT
+        medium.set_link(1, 0, true);
+        ptx.spi()
+            .execute(commands::WRegister(
+                registers::Status::new().with_max_rt(true),
+            ))
+            .unwrap();
+        ptx.spi().execute(commands::ReuseTxPl()).unwrap();
+        ptx.ce().set_high().unwrap();
+        medium.step();
+        assert!(read_status(&ptx).tx_ds());
+        assert_eq!(prx.rx_fifo_len(), 1);
+    }
+
+    #[test]
+    fn test_ack_payload() {
+        let ptx = Nrf24l01::new();
+        let prx = Nrf24l01::new();
+        let medium = Medium::new([&ptx, &prx]);
+        power_up(&ptx, false);
+        power_up(&prx, true);
+        enable_dynamic_payloads(&ptx);
+        enable_dynamic_payloads(&prx);
+
+        prx.spi()
+            .execute(commands::WAckPayload {
+                pipe: fields::Pipe::P0,
+                payload: [7, 8, 9],
+            })
+            .unwrap();
+        send(&ptx, &[1, 2]);
+        medium.step();
+
+        let status = read_status(&ptx);
+        assert!(status.tx_ds());
+        assert!(status.rx_dr());
+        let response = ptx.spi().execute(commands::RRxPlWid()).unwrap();
+        assert_eq!(response.width, 3);
+        let response = ptx.spi().execute(commands::RRxPayload::<3>()).unwrap();
+        assert_eq!(response.payload, [7, 8, 9]);
+        assert_eq!(prx.tx_fifo_len(), 0);
+        assert_eq!(prx.rx_fifo_len(), 1);
+    }
+
+    #[test]
+    fn test_max_rt_keeps_pid() {
+        let ptx = Nrf24l01::new();
+        let prx = Nrf24l01::new();
+        let medium = Medium::new([&ptx, &prx]);
+        power_up(&ptx, false);
+        power_up(&prx, true);
+        prx.spi()
+            .execute(commands::WRegister(
+                registers::RxPwP0::new().with_rx_pw_p0(2),
+            ))
+            .unwrap();
+
+        medium.set_link(1, 0, false);
+        send(&ptx, &[1, 2]);
+        medium.step();
+        assert!(read_status(&ptx).max_rt());
+        assert_eq!(prx.rx_fifo_len(), 1);
+
+        // The head is sent again with the same PID after clearing MAX_RT and pulsing CE
+        medium.set_link(1, 0, true);
+        ptx.spi()
+            .execute(commands::WRegister(
+                registers::Status::new().with_max_rt(true),
+            ))
+            .unwrap();
+        ptx.ce().set_high().unwrap();
+        ptx.ce().set_low().unwrap();
+        medium.step();
+        assert!(read_status(&ptx).tx_ds());
+        assert_eq!(ptx.tx_fifo_len(), 0);
+        assert_eq!(prx.rx_fifo_len(), 1);
+
+        // The next payload gets a new PID
+        send(&ptx, &[1, 2]);
+        medium.step();
+        assert_eq!(prx.rx_fifo_len(), 2);
+    }
+
+    #[test]
+    fn test_ack_payload_ard() {
+        let ptx = Nrf24l01::new();
+        let prx = Nrf24l01::new();
+        let medium = Medium::new([&ptx, &prx]);
+        power_up(&ptx, false);
+        power_up(&prx, true);
+        enable_dynamic_payloads(&ptx);
+        enable_dynamic_payloads(&prx);
+        let rf_setup = registers::RfSetup::new().with_rf_dr_high(fields::RfDrHigh::Mbps1);
+        ptx.spi().execute(commands::WRegister(rf_setup)).unwrap();
+        prx.spi().execute(commands::WRegister(rf_setup)).unwrap();
+
+        // An 8 byte ACK payload at 1Mbps doesn't fit in 250µs, the retransmit gets a plain ACK
+        prx.spi()
+            .execute(commands::WAckPayload {
+                pipe: fields::Pipe::P0,
+                payload: [7; 8],
+            })
+            .unwrap();
+        send(&ptx, &[1, 2]);
+        medium.step();
+        let status = read_status(&ptx);
+        assert!(status.tx_ds());
+        assert!(!status.rx_dr());
+        assert_eq!(ptx.register::<registers::ObserveTx>().arc_cnt(), 1);
+        assert_eq!(prx.tx_fifo_len(), 0);
+
+        // 500µs fits it
+        let setup_retr = registers::SetupRetr::new().with_ard(fields::AutoRetransmitDelay::US500);
+        ptx.spi().execute(commands::WRegister(setup_retr)).unwrap();
+        prx.spi()
+            .execute(commands::WAckPayload {
+                pipe: fields::Pipe::P0,
+                payload: [7; 8],
+            })
+            .unwrap();
+        send(&ptx, &[3, 4]);
+        medium.step();
+        assert!(read_status(&ptx).rx_dr());
+        assert_eq!(ptx.register::<registers::ObserveTx>().arc_cnt(), 0);
+        assert_eq!(ptx.rx_fifo_len(), 1);
+    }
+
+    #[test]
+    fn test_ack_payload_disabled() {
+        let ptx = Nrf24l01::new();
+        let prx = Nrf24l01::new();
+        let medium = Medium::new([&ptx, &prx]);
+        power_up(&ptx, false);
+        power_up(&prx, true);
+        enable_dynamic_payloads(&prx);
+
+        // The PTX has EN_DPL and EN_ACK_PAY off
+        prx.spi()
+            .execute(commands::WAckPayload {
+                pipe: fields::Pipe::P0,
+                payload: [7, 8, 9],
+            })
+            .unwrap();
+        send(&ptx, &[1, 2]);
+        medium.step();
+        let status = read_status(&ptx);
+        assert!(status.tx_ds());
+        assert!(!status.rx_dr());
+        assert_eq!(ptx.rx_fifo_len(), 0);
+    }
+
+    #[test]
+    fn test_no_ack() {
+        let ptx = Nrf24l01::new();
+        let medium = Medium::new([&ptx]);
+        power_up(&ptx, false);
+        enable_dynamic_payloads(&ptx);
+
+        ptx.spi().execute(commands::WTxPayloadNoack([1])).unwrap();
+        ptx.ce().set_high().unwrap();
+        medium.step();
+        let status = read_status(&ptx);
+        assert!(status.tx_ds());
+        assert!(!status.max_rt());
+        assert_eq!(ptx.tx_fifo_len(), 0);
+    }
+
+    #[test]
+    fn test_data_rate_mismatch() {
+        let ptx = Nrf24l01::new();
+        let prx = Nrf24l01::new();
+        let medium = Medium::new([&ptx, &prx]);
+        power_up(&ptx, false);
+        power_up(&prx, true);
+        prx.spi()
+            .execute(commands::WRegister(
+                registers::RxPwP0::new().with_rx_pw_p0(2),
+            ))
+            .unwrap();
+        let rf_setup = registers::RfSetup::new().with_rf_dr_high(fields::RfDrHigh::Mbps1);
+        prx.spi().execute(commands::WRegister(rf_setup)).unwrap();
+
+        send(&ptx, &[1, 2]);
+        medium.step();
+        assert!(read_status(&ptx).max_rt());
+        assert_eq!(prx.rx_fifo_len(), 0);
+    }
+}
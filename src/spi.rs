@@ -0,0 +1,377 @@
+//! Execute nRF24L01+ commands on an [`embedded_hal::spi::SpiDevice`].
+//!
+//! Requires the `embedded-hal` feature.
+//!
+//! Every command in [`commands`] implements [`Execute`]. Executing a command performs a single
+//! SPI transaction and returns the command's [`Response`][Command::Response] decoded from MISO.
+//!
+//! ## Example
+//! ```rust
+//! use embedded_hal_mock::eh1::spi::{Mock, Transaction};
+//! use nrf24l01_commands::{commands, registers, spi::SpiDeviceExt};
+//!
+//! let mut spi = Mock::new(&[
+//!     Transaction::transaction_start(),
+//!     Transaction::transfer_in_place(vec![0x05, 0], vec![0b0000_1110, 85]),
+//!     Transaction::transaction_end(),
+//! ]);
+//!
+//! // Read RF_CH register
+//! let response = spi.execute(commands::RRegister::<registers::RfCh>::new()).unwrap();
+//! assert_eq!(response.status.into_bits(), 0b0000_1110);
+//! assert_eq!(response.register.rf_ch(), 85);
+//! spi.done();
+//! ```
+use crate::commands::{self, Command};
+use crate::registers::{self, AddressRegister, ReadableRegister, WritableRegister};
+use embedded_hal::spi::{Operation, SpiDevice};
+
+/// A trait for commands that can be executed on an [`SpiDevice`].
+pub trait Execute: Command {
+    /// Execute the command in a single SPI transaction and decode the response.
+    fn execute<SPI: SpiDevice + ?Sized>(self, spi: &mut SPI) -> Result<Self::Response, SPI::Error>;
+}
+
+/// Extension trait to execute commands on an [`SpiDevice`].
+pub trait SpiDeviceExt: SpiDevice {
+    /// Execute a command in a single SPI transaction and decode the response.
+    fn execute<C: Execute>(&mut self, command: C) -> Result<C::Response, Self::Error> {
+        command.execute(self)
+    }
+}
+
+impl<SPI: SpiDevice> SpiDeviceExt for SPI {}
+
+/// Send the command word, then perform `operation` in the same transaction.
+/// Returns the status register shifted out with the command word.
+#[inline]
+fn word_then<SPI: SpiDevice + ?Sized>(
+    spi: &mut SPI,
+    word: u8,
+    operation: Operation<'_, u8>,
+) -> Result<registers::Status, SPI::Error> {
+    let mut status = [0];
+    spi.transaction(&mut [Operation::Transfer(&mut status, &[word]), operation])?;
+    Ok(registers::Status::from_bits(status[0]))
+}
+
+impl<R: ReadableRegister> Execute for commands::RRegister<R> {
+    fn execute<SPI: SpiDevice + ?Sized>(self, spi: &mut SPI) -> Result<Self::Response, SPI::Error> {
+        let mut bytes = [Self::WORD | R::ADDRESS, 0];
+        spi.transfer_in_place(&mut bytes)?;
+        Ok(commands::RegisterResponse {
+            status: registers::Status::from_bits(bytes[0]),
+            register: R::from_bits(bytes[1]),
+        })
+    }
+}
+
+impl<R: WritableRegister> Execute for commands::WRegister<R> {
+    fn execute<SPI: SpiDevice + ?Sized>(self, spi: &mut SPI) -> Result<Self::Response, SPI::Error> {
+        let mut bytes = [Self::WORD | R::ADDRESS, self.0.into_bits() & R::WRITE_MASK];
+        spi.transfer_in_place(&mut bytes)?;
+        Ok(registers::Status::from_bits(bytes[0]))
+    }
+}
+
+macro_rules! impl_execute_address_register {
+    ($reg:ident) => {
+        impl<const N: usize> Execute for commands::RRegister<registers::$reg<N>> {
+            fn execute<SPI: SpiDevice + ?Sized>(
+                self,
+                spi: &mut SPI,
+            ) -> Result<Self::Response, SPI::Error> {
+                let mut addr = [0; N];
+                let word = Self::WORD | registers::$reg::<N>::ADDRESS;
+                let status = word_then(spi, word, Operation::Read(&mut addr))?;
+                Ok(commands::RegisterResponse {
+                    status,
+                    register: registers::$reg::<N>::from_bytes(addr),
+                })
+            }
+        }
+
+        impl<const N: usize> Execute for commands::WRegister<registers::$reg<N>> {
+            fn execute<SPI: SpiDevice + ?Sized>(
+                self,
+                spi: &mut SPI,
+            ) -> Result<Self::Response, SPI::Error> {
+                let word = Self::WORD | registers::$reg::<N>::ADDRESS;
+                word_then(spi, word, Operation::Write(&self.0.into_bytes()))
+            }
+        }
+    };
+}
+
+impl_execute_address_register!(RxAddrP0);
+impl_execute_address_register!(RxAddrP1);
+impl_execute_address_register!(TxAddr);
+
+impl<const N: usize> Execute for commands::RRxPayload<N> {
+    fn execute<SPI: SpiDevice + ?Sized>(self, spi: &mut SPI) -> Result<Self::Response, SPI::Error> {
+        let () = commands::PayloadWidth::<N>::ASSERT;
+        let mut payload = [0; N];
+        let status = word_then(spi, Self::WORD, Operation::Read(&mut payload))?;
+        Ok(commands::RxPayloadResponse { status, payload })
+    }
+}
+
+impl<const N: usize> Execute for commands::WTxPayload<N> {
+    fn execute<SPI: SpiDevice + ?Sized>(self, spi: &mut SPI) -> Result<Self::Response, SPI::Error> {
+        let () = commands::PayloadWidth::<N>::ASSERT;
+        word_then(spi, Self::WORD, Operation::Write(&self.0))
+    }
+}
+
+impl<const N: usize> Execute for commands::WAckPayload<N> {
+    fn execute<SPI: SpiDevice + ?Sized>(self, spi: &mut SPI) -> Result<Self::Response, SPI::Error> {
+        let () = commands::PayloadWidth::<N>::ASSERT;
+        word_then(
+            spi,
+            Self::WORD | self.pipe.into_bits(),
+            Operation::Write(&self.payload),
+        )
+    }
+}
+
+impl<const N: usize> Execute for commands::WTxPayloadNoack<N> {
+    fn execute<SPI: SpiDevice + ?Sized>(self, spi: &mut SPI) -> Result<Self::Response, SPI::Error> {
+        let () = commands::PayloadWidth::<N>::ASSERT;
+        word_then(spi, Self::WORD, Operation::Write(&self.0))
+    }
+}
+
+impl Execute for commands::RRxPayloadSlice<'_> {
+    fn execute<SPI: SpiDevice + ?Sized>(
+        mut self,
+        spi: &mut SPI,
+    ) -> Result<Self::Response, SPI::Error> {
+        word_then(spi, Self::WORD, Operation::Read(self.payload_mut()))
+    }
+}
+
+impl Execute for commands::WTxPayloadSlice<'_> {
+    fn execute<SPI: SpiDevice + ?Sized>(self, spi: &mut SPI) -> Result<Self::Response, SPI::Error> {
+        word_then(spi, Self::WORD, Operation::Write(self.payload()))
+    }
+}
+
+impl Execute for commands::WAckPayloadSlice<'_> {
+    fn execute<SPI: SpiDevice + ?Sized>(self, spi: &mut SPI) -> Result<Self::Response, SPI::Error> {
+        word_then(spi, self.word(), Operation::Write(self.payload()))
+    }
+}
+
+impl Execute for commands::WTxPayloadNoackSlice<'_> {
+    fn execute<SPI: SpiDevice + ?Sized>(self, spi: &mut SPI) -> Result<Self::Response, SPI::Error> {
+        word_then(spi, Self::WORD, Operation::Write(self.payload()))
+    }
+}
+
+impl Execute for commands::RRxPlWid {
+    fn execute<SPI: SpiDevice + ?Sized>(self, spi: &mut SPI) -> Result<Self::Response, SPI::Error> {
+        let mut bytes = Self::bytes();
+        spi.transfer_in_place(&mut bytes)?;
+        Ok(Self::parse(&bytes))
+    }
+}
+
+impl Execute for commands::ClearInterrupts {
+    fn execute<SPI: SpiDevice + ?Sized>(self, spi: &mut SPI) -> Result<Self::Response, SPI::Error> {
+        let mut bytes = self.bytes();
+        spi.transfer_in_place(&mut bytes)?;
+        Ok(Self::parse(&bytes))
+    }
+}
+
+macro_rules! impl_execute_single_byte {
+    ($cmd:ident) => {
+        impl Execute for commands::$cmd {
+            fn execute<SPI: SpiDevice + ?Sized>(
+                self,
+                spi: &mut SPI,
+            ) -> Result<Self::Response, SPI::Error> {
+                let mut bytes = Self::bytes();
+                spi.transfer_in_place(&mut bytes)?;
+                Ok(Self::parse(&bytes))
+            }
+        }
+    };
+}
+
+impl_execute_single_byte!(FlushTx);
+impl_execute_single_byte!(FlushRx);
+impl_execute_single_byte!(ReuseTxPl);
+impl_execute_single_byte!(Nop);
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use crate::fields;
+    use embedded_hal_mock::eh1::spi::{Mock, Transaction};
+    use std::vec;
+
+    #[test]
+    fn test_execute_register() {
+        let mut spi = Mock::new(&[
+            Transaction::transaction_start(),
+            Transaction::transfer_in_place(vec![0x00, 0], vec![0b0000_1110, 0b0000_1010]),
+            Transaction::transaction_end(),
+            Transaction::transaction_start(),
+            Transaction::transfer_in_place(vec![0b0010_0000, 0b0000_1011], vec![0b0000_1110, 0]),
+            Transaction::transaction_end(),
+        ]);
+
+        let response = spi
+            .execute(commands::RRegister::<registers::Config>::new())
+            .unwrap();
+        assert_eq!(response.status.rx_p_no(), fields::RxPipeNo::RxFifoEmpty);
+        assert!(response.register.pwr_up());
+        assert!(!response.register.prim_rx());
+
+        let config = response.register.with_prim_rx(true);
+        let status = spi.execute(commands::WRegister(config)).unwrap();
+        assert_eq!(status.into_bits(), 0b0000_1110);
+        spi.done();
+    }
+
+    #[test]
+    fn test_execute_address_register() {
+        let mut spi = Mock::new(&[
+            Transaction::transaction_start(),
+            Transaction::transfer(vec![0x10], vec![0b0000_1110]),
+            Transaction::read_vec(vec![0x0B, 0x32, 0x7C, 0xDE, 0x61]),
+            Transaction::transaction_end(),
+            Transaction::transaction_start(),
+            Transaction::transfer(vec![0b0010_0000 | 0x0A], vec![0b0000_1110]),
+            Transaction::write_vec(vec![0x0B, 0x32, 0x7C]),
+            Transaction::transaction_end(),
+        ]);
+
+        let response = spi
+            .execute(commands::RRegister::<registers::TxAddr<5>>::new())
+            .unwrap();
+        assert_eq!(response.register.tx_addr(), 0x61DE7C320B);
+
+        let rx_addr_p0 = registers::RxAddrP0::<3>::new().with_rx_addr_p0(0x7C320B);
+        spi.execute(commands::WRegister(rx_addr_p0)).unwrap();
+        spi.done();
+    }
+
+    #[test]
+    fn test_execute_payloads() {
+        let mut spi = Mock::new(&[
+            Transaction::transaction_start(),
+            Transaction::transfer(vec![0b1010_0000], vec![0b0000_1110]),
+            Transaction::write_vec(vec![1, 2, 3]),
+            Transaction::transaction_end(),
+            Transaction::transaction_start(),
+            Transaction::transfer_in_place(vec![0b0110_0000, 0], vec![0b0100_0010, 2]),
+            Transaction::transaction_end(),
+            Transaction::transaction_start(),
+            Transaction::transfer(vec![0b0110_0001], vec![0b0100_0010]),
+            Transaction::read_vec(vec![4, 5]),
+            Transaction::transaction_end(),
+            Transaction::transaction_start(),
+            Transaction::transfer(vec![0b1010_1000 | 1], vec![0b0000_1110]),
+            Transaction::write_vec(vec![6]),
+            Transaction::transaction_end(),
+        ]);
+
+        spi.execute(commands::WTxPayload([1, 2, 3])).unwrap();
+
+        let response = spi.execute(commands::RRxPlWid()).unwrap();
+        assert_eq!(response.status.rx_p_no(), fields::RxPipeNo::Pipe1);
+        assert_eq!(response.width, 2);
+
+        let response = spi.execute(commands::RRxPayload::<2>()).unwrap();
+        assert!(response.status.rx_dr());
+        assert_eq!(response.payload, [4, 5]);
+
+        spi.execute(commands::WAckPayload {
+            pipe: fields::Pipe::P1,
+            payload: [6],
+        })
+        .unwrap();
+        spi.done();
+    }
+
+    #[test]
+    fn test_execute_payload_slices() {
+        let mut spi = Mock::new(&[
+            Transaction::transaction_start(),
+            Transaction::transfer_in_place(vec![0b0110_0000, 0], vec![0b0100_0000, 3]),
+            Transaction::transaction_end(),
+            Transaction::transaction_start(),
+            Transaction::transfer(vec![0b0110_0001], vec![0b0100_0000]),
+            Transaction::read_vec(vec![7, 8, 9]),
+            Transaction::transaction_end(),
+            Transaction::transaction_start(),
+            Transaction::transfer(vec![0b1010_0000], vec![0b0000_1110]),
+            Transaction::write_vec(vec![1, 2]),
+            Transaction::transaction_end(),
+            Transaction::transaction_start(),
+            Transaction::transfer(vec![0b1011_0000], vec![0b0000_1110]),
+            Transaction::write_vec(vec![3]),
+            Transaction::transaction_end(),
+            Transaction::transaction_start(),
+            Transaction::transfer(vec![0b1010_1000 | 5], vec![0b0000_1110]),
+            Transaction::write_vec(vec![4, 5, 6, 7]),
+            Transaction::transaction_end(),
+        ]);
+
+        // Dynamic payload length
+        let width = spi.execute(commands::RRxPlWid()).unwrap().width as usize;
+        let mut payload = [0; 32];
+        let command = commands::RRxPayloadSlice::new(&mut payload[..width]).unwrap();
+        assert!(spi.execute(command).unwrap().rx_dr());
+        assert_eq!(payload[..width], [7, 8, 9]);
+
+        let command = commands::WTxPayloadSlice::new(&[1, 2]).unwrap();
+        spi.execute(command).unwrap();
+        let command = commands::WTxPayloadNoackSlice::new(&[3]).unwrap();
+        spi.execute(command).unwrap();
+        let command = commands::WAckPayloadSlice::new(fields::Pipe::P5, &[4, 5, 6, 7]).unwrap();
+        spi.execute(command).unwrap();
+        spi.done();
+    }
+
+    #[test]
+    fn test_execute_single_byte_commands() {
+        let mut spi = Mock::new(&[
+            Transaction::transaction_start(),
+            Transaction::transfer_in_place(vec![0b1110_0001], vec![0b0001_1110]),
+            Transaction::transaction_end(),
+            Transaction::transaction_start(),
+            Transaction::transfer_in_place(vec![0xFF], vec![0b0000_1110]),
+            Transaction::transaction_end(),
+        ]);
+
+        let status = spi.execute(commands::FlushTx()).unwrap();
+        assert!(status.max_rt());
+        let status = spi.execute(commands::Nop()).unwrap();
+        assert!(!status.max_rt());
+        spi.done();
+    }
+
+    #[test]
+    fn test_execute_clear_interrupts() {
+        let mut spi = Mock::new(&[
+            Transaction::transaction_start(),
+            Transaction::transfer_in_place(vec![0b0010_0111, 0b0100_0000], vec![0b0111_0010, 0]),
+            Transaction::transaction_end(),
+        ]);
+
+        let status = spi
+            .execute(commands::ClearInterrupts::only(fields::Interrupt::RxDr))
+            .unwrap();
+        let mut interrupts = status.interrupts();
+        assert_eq!(interrupts.next(), Some(fields::Interrupt::RxDr));
+        assert_eq!(interrupts.next(), Some(fields::Interrupt::TxDs));
+        assert_eq!(interrupts.next(), Some(fields::Interrupt::MaxRt));
+        assert_eq!(interrupts.next(), None);
+        spi.done();
+    }
+}
@@ -0,0 +1,370 @@
+//! Execute nRF24L01+ commands on an [`embedded_hal_async::spi::SpiDevice`].
+//!
+//! Requires the `embedded-hal-async` feature.
+//!
+//! This is the async counterpart of [`spi`](crate::spi). Every command in [`commands`]
+//! implements [`ExecuteAsync`] and returns the same [`Response`][Command::Response].
+//!
+//! ## Example
+//! ```rust
+//! use embedded_hal_mock::eh1::digital::{Mock as PinMock, State, Transaction as PinTransaction};
+//! use embedded_hal_mock::eh1::spi::{Mock as SpiMock, Transaction as SpiTransaction};
+//! use nrf24l01_commands::{commands, spi_async::{self, SpiDeviceExt}};
+//!
+//! let mut spi = SpiMock::new(&[
+//!     SpiTransaction::transaction_start(),
+//!     SpiTransaction::transfer(vec![0b1010_0000], vec![0b0000_1110]),
+//!     SpiTransaction::write_vec(vec![1, 2, 3]),
+//!     SpiTransaction::transaction_end(),
+//!     SpiTransaction::transaction_start(),
+//!     SpiTransaction::transfer_in_place(vec![0xFF], vec![0b0010_1110]),
+//!     SpiTransaction::transaction_end(),
+//! ]);
+//! let mut irq = PinMock::new(&[PinTransaction::wait_for_state(State::Low)]);
+//!
+//! embassy_futures::block_on(async {
+//!     // Write TX payload
+//!     spi.execute(commands::WTxPayload([1, 2, 3])).await.unwrap();
+//!     // Wait until the packet is sent
+//!     let status = spi_async::wait_irq(&mut spi, &mut irq).await.unwrap();
+//!     assert!(status.tx_ds());
+//! });
+//! spi.done();
+//! irq.done();
+//! ```
+use crate::commands::{self, Command};
+use crate::registers::{self, AddressRegister, ReadableRegister, WritableRegister};
+use embedded_hal_async::digital::Wait;
+use embedded_hal_async::spi::{Operation, SpiDevice};
+
+/// Error from waiting on the IRQ pin.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Error<SpiE, IrqE> {
+    /// SPI error.
+    Spi(SpiE),
+    /// IRQ pin error.
+    Irq(IrqE),
+}
+
+/// A trait for commands that can be executed on an async [`SpiDevice`].
+#[allow(async_fn_in_trait)]
+pub trait ExecuteAsync: Command {
+    /// Execute the command in a single SPI transaction and decode the response.
+    async fn execute<SPI: SpiDevice + ?Sized>(
+        self,
+        spi: &mut SPI,
+    ) -> Result<Self::Response, SPI::Error>;
+}
+
+/// Extension trait to execute commands on an async [`SpiDevice`].
+#[allow(async_fn_in_trait)]
+pub trait SpiDeviceExt: SpiDevice {
+    /// Execute a command in a single SPI transaction and decode the response.
+    async fn execute<C: ExecuteAsync>(&mut self, command: C) -> Result<C::Response, Self::Error> {
+        command.execute(self).await
+    }
+}
+
+impl<SPI: SpiDevice> SpiDeviceExt for SPI {}
+
+/// Wait for the active-low IRQ pin to be asserted, then read the
+/// [`STATUS`][registers::Status] register with [`NOP`][commands::Nop].
+///
+/// The returned status tells which of `rx_dr`, `tx_ds` and `max_rt` caused the interrupt.
+/// The flags are not cleared.
+pub async fn wait_irq<SPI, IRQ>(
+    spi: &mut SPI,
+    irq: &mut IRQ,
+) -> Result<registers::Status, Error<SPI::Error, IRQ::Error>>
+where
+    SPI: SpiDevice + ?Sized,
+    IRQ: Wait + ?Sized,
+{
+    irq.wait_for_low().await.map_err(Error::Irq)?;
+    commands::Nop().execute(spi).await.map_err(Error::Spi)
+}
+
+/// Send the command word, then perform `operation` in the same transaction.
+/// Returns the status register shifted out with the command word.
+#[inline]
+async fn word_then<SPI: SpiDevice + ?Sized>(
+    spi: &mut SPI,
+    word: u8,
+    operation: Operation<'_, u8>,
+) -> Result<registers::Status, SPI::Error> {
+    let mut status = [0];
+    spi.transaction(&mut [Operation::Transfer(&mut status, &[word]), operation])
+        .await?;
+    Ok(registers::Status::from_bits(status[0]))
+}
+
+impl<R: ReadableRegister> ExecuteAsync for commands::RRegister<R> {
+    async fn execute<SPI: SpiDevice + ?Sized>(
+        self,
+        spi: &mut SPI,
+    ) -> Result<Self::Response, SPI::Error> {
+        let mut bytes = [Self::WORD | R::ADDRESS, 0];
+        spi.transfer_in_place(&mut bytes).await?;
+        Ok(commands::RegisterResponse {
+            status: registers::Status::from_bits(bytes[0]),
+            register: R::from_bits(bytes[1]),
+        })
+    }
+}
+
+impl<R: WritableRegister> ExecuteAsync for commands::WRegister<R> {
+    async fn execute<SPI: SpiDevice + ?Sized>(
+        self,
+        spi: &mut SPI,
+    ) -> Result<Self::Response, SPI::Error> {
+        let mut bytes = [Self::WORD | R::ADDRESS, self.0.into_bits() & R::WRITE_MASK];
+        spi.transfer_in_place(&mut bytes).await?;
+        Ok(registers::Status::from_bits(bytes[0]))
+    }
+}
+
+macro_rules! impl_execute_address_register {
+    ($reg:ident) => {
+        impl<const N: usize> ExecuteAsync for commands::RRegister<registers::$reg<N>> {
+            async fn execute<SPI: SpiDevice + ?Sized>(
+                self,
+                spi: &mut SPI,
+            ) -> Result<Self::Response, SPI::Error> {
+                let mut addr = [0; N];
+                let word = Self::WORD | registers::$reg::<N>::ADDRESS;
+                let status = word_then(spi, word, Operation::Read(&mut addr)).await?;
+                Ok(commands::RegisterResponse {
+                    status,
+                    register: registers::$reg::<N>::from_bytes(addr),
+                })
+            }
+        }
+
+        impl<const N: usize> ExecuteAsync for commands::WRegister<registers::$reg<N>> {
+            async fn execute<SPI: SpiDevice + ?Sized>(
+                self,
+                spi: &mut SPI,
+            ) -> Result<Self::Response, SPI::Error> {
+                let word = Self::WORD | registers::$reg::<N>::ADDRESS;
+                word_then(spi, word, Operation::Write(&self.0.into_bytes())).await
+            }
+        }
+    };
+}
+
+impl_execute_address_register!(RxAddrP0);
+impl_execute_address_register!(RxAddrP1);
+impl_execute_address_register!(TxAddr);
+
+impl<const N: usize> ExecuteAsync for commands::RRxPayload<N> {
+    async fn execute<SPI: SpiDevice + ?Sized>(
+        self,
+        spi: &mut SPI,
+    ) -> Result<Self::Response, SPI::Error> {
+        let () = commands::PayloadWidth::<N>::ASSERT;
+        let mut payload = [0; N];
+        let status = word_then(spi, Self::WORD, Operation::Read(&mut payload)).await?;
+        Ok(commands::RxPayloadResponse { status, payload })
+    }
+}
+
+impl<const N: usize> ExecuteAsync for commands::WTxPayload<N> {
+    async fn execute<SPI: SpiDevice + ?Sized>(
+        self,
+        spi: &mut SPI,
+    ) -> Result<Self::Response, SPI::Error> {
+        let () = commands::PayloadWidth::<N>::ASSERT;
+        word_then(spi, Self::WORD, Operation::Write(&self.0)).await
+    }
+}
+
+impl<const N: usize> ExecuteAsync for commands::WAckPayload<N> {
+    async fn execute<SPI: SpiDevice + ?Sized>(
+        self,
+        spi: &mut SPI,
+    ) -> Result<Self::Response, SPI::Error> {
+        let () = commands::PayloadWidth::<N>::ASSERT;
+        word_then(
+            spi,
+            Self::WORD | self.pipe.into_bits(),
+            Operation::Write(&self.payload),
+        )
+        .await
+    }
+}
+
+impl<const N: usize> ExecuteAsync for commands::WTxPayloadNoack<N> {
+    async fn execute<SPI: SpiDevice + ?Sized>(
+        self,
+        spi: &mut SPI,
+    ) -> Result<Self::Response, SPI::Error> {
+        let () = commands::PayloadWidth::<N>::ASSERT;
+        word_then(spi, Self::WORD, Operation::Write(&self.0)).await
+    }
+}
+
+impl ExecuteAsync for commands::RRxPayloadSlice<'_> {
+    async fn execute<SPI: SpiDevice + ?Sized>(
+        mut self,
+        spi: &mut SPI,
+    ) -> Result<Self::Response, SPI::Error> {
+        word_then(spi, Self::WORD, Operation::Read(self.payload_mut())).await
+    }
+}
+
+impl ExecuteAsync for commands::WTxPayloadSlice<'_> {
+    async fn execute<SPI: SpiDevice + ?Sized>(
+        self,
+        spi: &mut SPI,
+    ) -> Result<Self::Response, SPI::Error> {
+        word_then(spi, Self::WORD, Operation::Write(self.payload())).await
+    }
+}
+
+impl ExecuteAsync for commands::WAckPayloadSlice<'_> {
+    async fn execute<SPI: SpiDevice + ?Sized>(
+        self,
+        spi: &mut SPI,
+    ) -> Result<Self::Response, SPI::Error> {
+        word_then(spi, self.word(), Operation::Write(self.payload())).await
+    }
+}
+
+impl ExecuteAsync for commands::WTxPayloadNoackSlice<'_> {
+    async fn execute<SPI: SpiDevice + ?Sized>(
+        self,
+        spi: &mut SPI,
+    ) -> Result<Self::Response, SPI::Error> {
+        word_then(spi, Self::WORD, Operation::Write(self.payload())).await
+    }
+}
+
+impl ExecuteAsync for commands::RRxPlWid {
+    async fn execute<SPI: SpiDevice + ?Sized>(
+        self,
+        spi: &mut SPI,
+    ) -> Result<Self::Response, SPI::Error> {
+        let mut bytes = Self::bytes();
+        spi.transfer_in_place(&mut bytes).await?;
+        Ok(Self::parse(&bytes))
+    }
+}
+
+impl ExecuteAsync for commands::ClearInterrupts {
+    async fn execute<SPI: SpiDevice + ?Sized>(
+        self,
+        spi: &mut SPI,
+    ) -> Result<Self::Response, SPI::Error> {
+        let mut bytes = self.bytes();
+        spi.transfer_in_place(&mut bytes).await?;
+        Ok(Self::parse(&bytes))
+    }
+}
+
+macro_rules! impl_execute_single_byte {
+    ($cmd:ident) => {
+        impl ExecuteAsync for commands::$cmd {
+            async fn execute<SPI: SpiDevice + ?Sized>(
+                self,
+                spi: &mut SPI,
+            ) -> Result<Self::Response, SPI::Error> {
+                let mut bytes = Self::bytes();
+                spi.transfer_in_place(&mut bytes).await?;
+                Ok(Self::parse(&bytes))
+            }
+        }
+    };
+}
+
+impl_execute_single_byte!(FlushTx);
+impl_execute_single_byte!(FlushRx);
+impl_execute_single_byte!(ReuseTxPl);
+impl_execute_single_byte!(Nop);
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use crate::fields;
+    use embassy_futures::block_on;
+    use embedded_hal_mock::eh1::digital::{Mock as PinMock, State, Transaction as PinTransaction};
+    use embedded_hal_mock::eh1::spi::{Mock as SpiMock, Transaction as SpiTransaction};
+    use std::vec;
+
+    #[test]
+    fn test_execute_registers() {
+        let mut spi = SpiMock::new(&[
+            SpiTransaction::transaction_start(),
+            SpiTransaction::transfer_in_place(vec![0x06, 0], vec![0b0000_1110, 0b0010_0100]),
+            SpiTransaction::transaction_end(),
+            SpiTransaction::transaction_start(),
+            SpiTransaction::transfer(vec![0b0010_0000 | 0x10], vec![0b0000_1110]),
+            SpiTransaction::write_vec(vec![0x6A, 0xFF, 0x1F, 0x89]),
+            SpiTransaction::transaction_end(),
+            SpiTransaction::transaction_start(),
+            SpiTransaction::transfer(vec![0x0B], vec![0b0000_1110]),
+            SpiTransaction::read_vec(vec![0xC2, 0xC2, 0xC2, 0xC2]),
+            SpiTransaction::transaction_end(),
+        ]);
+
+        block_on(async {
+            let response = spi
+                .execute(commands::RRegister::<registers::RfSetup>::new())
+                .await
+                .unwrap();
+            assert!(response.register.rf_dr_low());
+            assert_eq!(response.register.rf_pwr(), fields::RfPower::Neg6Dbm);
+
+            let tx_addr = registers::TxAddr::<4>::new().with_tx_addr(0x891FFF6A);
+            spi.execute(commands::WRegister(tx_addr)).await.unwrap();
+
+            let response = spi
+                .execute(commands::RRegister::<registers::RxAddrP1<4>>::new())
+                .await
+                .unwrap();
+            assert_eq!(response.register.rx_addr_p1(), 0xC2C2C2C2);
+        });
+        spi.done();
+    }
+
+    #[test]
+    fn test_execute_rx_payload() {
+        let mut spi = SpiMock::new(&[
+            SpiTransaction::transaction_start(),
+            SpiTransaction::transfer_in_place(vec![0b0110_0000, 0], vec![0b0100_0000, 3]),
+            SpiTransaction::transaction_end(),
+            SpiTransaction::transaction_start(),
+            SpiTransaction::transfer(vec![0b0110_0001], vec![0b0100_0000]),
+            SpiTransaction::read_vec(vec![7, 8, 9]),
+            SpiTransaction::transaction_end(),
+        ]);
+
+        block_on(async {
+            let response = spi.execute(commands::RRxPlWid()).await.unwrap();
+            assert_eq!(response.width, 3);
+            let response = spi.execute(commands::RRxPayload::<3>()).await.unwrap();
+            assert_eq!(response.status.rx_p_no(), fields::RxPipeNo::Pipe0);
+            assert_eq!(response.payload, [7, 8, 9]);
+        });
+        spi.done();
+    }
+
+    #[test]
+    fn test_wait_irq() {
+        let mut spi = SpiMock::new(&[
+            SpiTransaction::transaction_start(),
+            SpiTransaction::transfer_in_place(vec![0xFF], vec![0b0101_0010]),
+            SpiTransaction::transaction_end(),
+        ]);
+        let mut irq = PinMock::new(&[PinTransaction::wait_for_state(State::Low)]);
+
+        let status = block_on(wait_irq(&mut spi, &mut irq)).unwrap();
+        assert!(status.rx_dr());
+        assert!(!status.tx_ds());
+        assert!(status.max_rt());
+        assert_eq!(status.rx_p_no(), fields::RxPipeNo::Pipe1);
+        spi.done();
+        irq.done();
+    }
+}
@@ -0,0 +1,204 @@
+//! Timing requirements from the datasheet and a CE/delay sequencer.
+//!
+//! | Constant             | Datasheet   | Value   | Description                                  |
+//! |----------------------|-------------|---------|----------------------------------------------|
+//! | [`TPD2STBY_US`]      | `Tpd2stby`  | 1.5 ms  | Power down to standby, external crystal      |
+//! | [`TSTBY2A_US`]       | `Tstby2a`   | 130 µs  | Standby to TX or RX mode                     |
+//! | [`THCE_US`]          | `Thce`      | 10 µs   | Minimum CE high pulse to transmit a packet   |
+//! | [`TPECE2CSN_US`]     | `Tpece2csn` | 4 µs    | Delay from CE rising edge to CSN low         |
+//! | [`TX_MODE_MAX_US`]   |             | 4 ms    | Maximum time in TX mode                      |
+//!
+//! The [`Sequencer`] (feature `embedded-hal`) drives the CE pin with these delays.
+//!
+//! ## Example
+//! ```rust
+//! use nrf24l01_commands::{registers, timing};
+//!
+//! // Setting pwr_up requires waiting for the oscillator to start up
+//! let power_down = registers::Config::new();
+//! let power_up = power_down.with_pwr_up(true);
+//! assert_eq!(timing::config_delay_us(power_down, power_up), timing::TPD2STBY_US);
+//! assert_eq!(timing::config_delay_us(power_up, power_up.with_prim_rx(true)), 0);
+//! ```
+use crate::registers;
+#[cfg(feature = "embedded-hal")]
+use embedded_hal::{delay::DelayNs, digital::OutputPin};
+
+/// Time from power down to standby mode (`Tpd2stby`), in microseconds.
+/// Worst case with an external crystal.
+pub const TPD2STBY_US: u32 = 1500;
+/// Time from power down to standby mode (`Tpd2stby`) with an external clock, in microseconds.
+pub const TPD2STBY_EXTERNAL_CLOCK_US: u32 = 150;
+/// Time from standby to TX or RX mode (`Tstby2a`), in microseconds.
+pub const TSTBY2A_US: u32 = 130;
+/// Minimum CE high pulse to transmit a packet (`Thce`), in microseconds.
+pub const THCE_US: u32 = 10;
+/// Minimum delay from CE rising edge to CSN low (`Tpece2csn`), in microseconds.
+pub const TPECE2CSN_US: u32 = 4;
+/// Maximum time in TX mode, in microseconds. The chip is never in TX mode longer than this
+/// with Enhanced ShockBurst, but CE must not be held high longer than this without it.
+pub const TX_MODE_MAX_US: u32 = 4000;
+
+/// Clock source of the chip.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Clock {
+    /// External crystal.
+    #[default]
+    Crystal,
+    /// External clock driving XC1.
+    External,
+}
+
+impl Clock {
+    /// Time from power down to standby mode (`Tpd2stby`), in microseconds.
+    pub const fn tpd2stby_us(self) -> u32 {
+        match self {
+            Self::Crystal => TPD2STBY_US,
+            Self::External => TPD2STBY_EXTERNAL_CLOCK_US,
+        }
+    }
+}
+
+/// Time to wait after writing `config` to a chip whose [`CONFIG`](registers::Config) was
+/// `previous`, with an external crystal, in microseconds.
+///
+/// Setting `pwr_up` requires [`TPD2STBY_US`]. Other changes take effect immediately.
+pub const fn config_delay_us(previous: registers::Config, config: registers::Config) -> u32 {
+    if !previous.pwr_up() && config.pwr_up() {
+        TPD2STBY_US
+    } else {
+        0
+    }
+}
+
+/// Time until the chip is in TX or RX mode after CE is set high with `config`, in
+/// microseconds. Zero when powered down, since the chip stays in power down.
+pub const fn ce_high_delay_us(config: registers::Config) -> u32 {
+    if config.pwr_up() { TSTBY2A_US } else { 0 }
+}
+
+/// Drives the CE pin with the delays the datasheet requires.
+///
+/// Requires the `embedded-hal` feature.
+///
+/// #### Type Parameters
+/// - `CE`: CE output pin.
+/// - `D`: delay provider.
+#[cfg(feature = "embedded-hal")]
+#[derive(Debug)]
+pub struct Sequencer<CE, D> {
+    ce: CE,
+    delay: D,
+    clock: Clock,
+}
+
+#[cfg(feature = "embedded-hal")]
+impl<CE: OutputPin, D: DelayNs> Sequencer<CE, D> {
+    /// Create a sequencer for a chip with an external crystal.
+    pub fn new(ce: CE, delay: D) -> Self {
+        Self::with_clock(ce, delay, Clock::Crystal)
+    }
+
+    /// Create a sequencer for a chip with the given clock source.
+    pub fn with_clock(ce: CE, delay: D, clock: Clock) -> Self {
+        Self { ce, delay, clock }
+    }
+
+    /// Power-up: set CE low and wait for the oscillator to start up.
+    /// Call after writing `pwr_up` to [`CONFIG`](registers::Config).
+    pub fn power_up(&mut self) -> Result<(), CE::Error> {
+        self.ce.set_low()?;
+        self.delay.delay_us(self.clock.tpd2stby_us());
+        Ok(())
+    }
+
+    /// Transmit-pulse: pulse CE high for [`THCE_US`] to transmit the top TX payload.
+    /// The chip returns to Standby-I once the packet is sent.
+    pub fn transmit_pulse(&mut self) -> Result<(), CE::Error> {
+        self.ce.set_high()?;
+        self.delay.delay_us(THCE_US);
+        self.ce.set_low()
+    }
+
+    /// RX-enter: set CE high and wait until the chip is in RX mode.
+    /// Call with `prim_rx` set in [`CONFIG`](registers::Config).
+    pub fn enter_rx(&mut self) -> Result<(), CE::Error> {
+        self.enter_active()
+    }
+
+    /// TX-enter: set CE high and wait until the chip is in TX mode, or Standby-II if the TX
+    /// FIFO is empty. Call with `prim_rx` cleared in [`CONFIG`](registers::Config).
+    pub fn enter_tx(&mut self) -> Result<(), CE::Error> {
+        self.enter_active()
+    }
+
+    /// Set CE low to return to Standby-I.
+    pub fn standby(&mut self) -> Result<(), CE::Error> {
+        self.ce.set_low()
+    }
+
+    /// Delay provider, e.g. to wait before the next command.
+    pub fn delay(&mut self) -> &mut D {
+        &mut self.delay
+    }
+
+    /// Release the CE pin and delay provider.
+    pub fn release(self) -> (CE, D) {
+        (self.ce, self.delay)
+    }
+
+    fn enter_active(&mut self) -> Result<(), CE::Error> {
+        self.ce.set_high()?;
+        self.delay.delay_us(TSTBY2A_US);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_delays() {
+        let power_down = registers::Config::new();
+        let standby = power_down.with_pwr_up(true);
+        assert_eq!(config_delay_us(power_down, standby), 1500);
+        assert_eq!(config_delay_us(standby, standby.with_prim_rx(true)), 0);
+        assert_eq!(config_delay_us(standby, power_down), 0);
+        assert_eq!(ce_high_delay_us(standby), 130);
+        assert_eq!(ce_high_delay_us(power_down), 0);
+        assert_eq!(Clock::External.tpd2stby_us(), 150);
+    }
+
+    #[test]
+    #[cfg(feature = "embedded-hal")]
+    fn test_sequencer() {
+        use embedded_hal_mock::eh1::delay::{CheckedDelay, Transaction as DelayTransaction};
+        use embedded_hal_mock::eh1::digital::{
+            Mock as PinMock, State, Transaction as PinTransaction,
+        };
+
+        let ce = PinMock::new(&[
+            PinTransaction::set(State::Low),
+            PinTransaction::set(State::High),
+            PinTransaction::set(State::Low),
+            PinTransaction::set(State::High),
+            PinTransaction::set(State::Low),
+        ]);
+        let delay = CheckedDelay::new(&[
+            DelayTransaction::delay_us(1500),
+            DelayTransaction::delay_us(10),
+            DelayTransaction::delay_us(130),
+        ]);
+
+        let mut sequencer = Sequencer::new(ce, delay);
+        sequencer.power_up().unwrap();
+        sequencer.transmit_pulse().unwrap();
+        sequencer.enter_rx().unwrap();
+        sequencer.standby().unwrap();
+
+        let (mut ce, mut delay) = sequencer.release();
+        ce.done();
+        delay.done();
+    }
+}
@@ -0,0 +1,535 @@
+//! Disassembler for SPI traces, e.g. MOSI/MISO captures from a logic analyzer.
+//!
+//! Each SPI transaction, i.e. the bytes exchanged during one CSN assertion, is decoded into a
+//! [`Transaction`] naming the command and the [`registers`] type of the register it accesses.
+//! The [`Disassembler`] follows [`SETUP_AW`](registers::SetupAw) across the trace to decode
+//! the 3-5 byte address registers.
+//!
+//! ## Example
+//! ```rust
+//! extern crate std;
+//! use nrf24l01_commands::trace::Disassembler;
+//! use std::string::ToString;
+//!
+//! let mut disassembler = Disassembler::new();
+//!
+//! let transaction = disassembler
+//!     .decode(&[0b0010_0000, 0b0000_1010], &[0b0000_1110, 0])
+//!     .unwrap();
+//! assert_eq!(
+//!     transaction.to_string(),
+//!     "W_REGISTER CONFIG { mask_rx_dr: false, mask_tx_ds: false, mask_max_rt: false, \
+//!      en_crc: true, crco: OneByte, pwr_up: true, prim_rx: false }"
+//! );
+//!
+//! let transaction = disassembler
+//!     .decode(&[0b1010_0000, 1, 2, 3], &[0b0000_1110, 0, 0, 0])
+//!     .unwrap();
+//! assert_eq!(transaction.to_string(), "W_TX_PAYLOAD [3 bytes]");
+//! ```
+use crate::commands::{self, Command};
+use crate::fields::{AddressWidth, Pipe};
+use crate::registers::{self, AddressRegister, Register};
+use core::fmt;
+
+/// Error decoding an SPI transaction.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// Transaction is empty.
+    Empty,
+    /// MOSI and MISO have different lengths.
+    LengthMismatch {
+        /// Number of MOSI bytes.
+        mosi: usize,
+        /// Number of MISO bytes.
+        miso: usize,
+    },
+    /// Transaction ended before the command's data bytes.
+    Truncated {
+        /// Command word.
+        word: u8,
+        /// Length of the transaction.
+        len: usize,
+        /// Length of the command's SPI byte sequence.
+        expected: usize,
+    },
+    /// An address register is accessed while [`SETUP_AW`](registers::SetupAw) is
+    /// [`AddressWidth::Illegal`].
+    IllegalAddressWidth,
+}
+
+/// Address register value.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Address {
+    /// Address, LSByte first on SPI.
+    pub bits: u64,
+    /// Address width in bytes.
+    pub width: usize,
+}
+
+impl Address {
+    /// Decode the address from bytes ordered by LSByte first.
+    fn from_bytes(bytes: &[u8]) -> Self {
+        let mut le_bytes = [0; 8];
+        le_bytes[..bytes.len()].copy_from_slice(bytes);
+        Self {
+            bits: u64::from_le_bytes(le_bytes),
+            width: bytes.len(),
+        }
+    }
+}
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#0w$X}", self.bits, w = 2 + 2 * self.width)
+    }
+}
+
+/// Writes the `Debug` output of a register without its type name.
+struct Fields<'a, 'b> {
+    f: &'a mut fmt::Formatter<'b>,
+    started: bool,
+}
+
+impl fmt::Write for Fields<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.started {
+            return self.f.write_str(s);
+        }
+        match s.find('{') {
+            Some(i) => {
+                self.started = true;
+                self.f.write_str(&s[i..])
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+/// Write the fields of a register.
+fn write_fields(f: &mut fmt::Formatter<'_>, register: &dyn fmt::Debug) -> fmt::Result {
+    fmt::write(
+        &mut Fields { f, started: false },
+        format_args!("{register:?}"),
+    )
+}
+
+macro_rules! register_values {
+    ($($reg:ident => $name:literal),* $(,)?) => {
+        /// Value of a register, decoded into its [`registers`] type.
+        #[derive(Copy, Clone, Debug)]
+        pub enum RegisterValue {
+            $(
+                #[doc = concat!("`", $name, "`")]
+                $reg(registers::$reg),
+            )*
+            /// `RX_ADDR_P0`
+            RxAddrP0(Address),
+            /// `RX_ADDR_P1`
+            RxAddrP1(Address),
+            /// `TX_ADDR`
+            TxAddr(Address),
+        }
+
+        impl RegisterValue {
+            /// Decode a single byte register.
+            fn from_byte(address: u8, bits: u8) -> Option<Self> {
+                match address {
+                    $(registers::$reg::ADDRESS => Some(Self::$reg(registers::$reg::from_bits(bits))),)*
+                    _ => None,
+                }
+            }
+
+            /// Register name as in the datasheet.
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(Self::$reg(_) => $name,)*
+                    Self::RxAddrP0(_) => "RX_ADDR_P0",
+                    Self::RxAddrP1(_) => "RX_ADDR_P1",
+                    Self::TxAddr(_) => "TX_ADDR",
+                }
+            }
+
+            /// Write the register's fields, or the address of an address register.
+            fn fmt_value(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                match self {
+                    $(Self::$reg(register) => write_fields(f, register),)*
+                    Self::RxAddrP0(address) | Self::RxAddrP1(address) | Self::TxAddr(address) => {
+                        write!(f, "{address}")
+                    }
+                }
+            }
+        }
+
+        impl fmt::Display for RegisterValue {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{} ", self.name())?;
+                self.fmt_value(f)
+            }
+        }
+    };
+}
+
+register_values! {
+    Config => "CONFIG",
+    EnAa => "EN_AA",
+    EnRxaddr => "EN_RXADDR",
+    SetupAw => "SETUP_AW",
+    SetupRetr => "SETUP_RETR",
+    RfCh => "RF_CH",
+    RfSetup => "RF_SETUP",
+    Status => "STATUS",
+    ObserveTx => "OBSERVE_TX",
+    Rpd => "RPD",
+    RxAddrP2 => "RX_ADDR_P2",
+    RxAddrP3 => "RX_ADDR_P3",
+    RxAddrP4 => "RX_ADDR_P4",
+    RxAddrP5 => "RX_ADDR_P5",
+    RxPwP0 => "RX_PW_P0",
+    RxPwP1 => "RX_PW_P1",
+    RxPwP2 => "RX_PW_P2",
+    RxPwP3 => "RX_PW_P3",
+    RxPwP4 => "RX_PW_P4",
+    RxPwP5 => "RX_PW_P5",
+    FifoStatus => "FIFO_STATUS",
+    Dynpd => "DYNPD",
+    Feature => "FEATURE",
+}
+
+/// Decoded command of an SPI transaction.
+///
+/// Data of read commands is decoded from MISO, data of write commands from MOSI.
+#[derive(Copy, Clone, Debug)]
+pub enum Decoded<'a> {
+    /// [`R_REGISTER`](commands::RRegister) and the register that was read.
+    RRegister(RegisterValue),
+    /// [`W_REGISTER`](commands::WRegister) and the register that was written.
+    WRegister(RegisterValue),
+    /// `R_REGISTER` or `W_REGISTER` on an undefined register address.
+    UnknownRegister {
+        /// Command word.
+        word: u8,
+    },
+    /// [`R_RX_PAYLOAD`](commands::RRxPayload) and the payload that was read.
+    RRxPayload(&'a [u8]),
+    /// [`W_TX_PAYLOAD`](commands::WTxPayload) and the payload that was written.
+    WTxPayload(&'a [u8]),
+    /// [`FLUSH_TX`](commands::FlushTx)
+    FlushTx,
+    /// [`FLUSH_RX`](commands::FlushRx)
+    FlushRx,
+    /// [`REUSE_TX_PL`](commands::ReuseTxPl)
+    ReuseTxPl,
+    /// [`R_RX_PL_WID`](commands::RRxPlWid) and the width that was read.
+    RRxPlWid(u8),
+    /// [`W_ACK_PAYLOAD`](commands::WAckPayload) and the payload that was written.
+    WAckPayload {
+        /// Data pipe of the ACK payload.
+        pipe: Pipe,
+        /// Payload.
+        payload: &'a [u8],
+    },
+    /// [`W_TX_PAYLOAD_NOACK`](commands::WTxPayloadNoack) and the payload that was written.
+    WTxPayloadNoack(&'a [u8]),
+    /// [`NOP`](commands::Nop)
+    Nop,
+    /// Undefined command word.
+    Unknown {
+        /// Command word.
+        word: u8,
+    },
+}
+
+/// A decoded SPI transaction.
+#[derive(Copy, Clone, Debug)]
+pub struct Transaction<'a> {
+    /// Status register shifted out while the command word is sent.
+    pub status: registers::Status,
+    /// Decoded command.
+    pub command: Decoded<'a>,
+}
+
+impl fmt::Display for Transaction<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.command {
+            Decoded::RRegister(register) => {
+                write!(f, "R_REGISTER {} -> ", register.name())?;
+                register.fmt_value(f)
+            }
+            Decoded::WRegister(register) => write!(f, "W_REGISTER {register}"),
+            Decoded::UnknownRegister { word } => write!(f, "REGISTER {word:#04X}"),
+            Decoded::RRxPayload(payload) => write!(f, "R_RX_PAYLOAD -> [{} bytes]", payload.len()),
+            Decoded::WTxPayload(payload) => write!(f, "W_TX_PAYLOAD [{} bytes]", payload.len()),
+            Decoded::FlushTx => write!(f, "FLUSH_TX"),
+            Decoded::FlushRx => write!(f, "FLUSH_RX"),
+            Decoded::ReuseTxPl => write!(f, "REUSE_TX_PL"),
+            Decoded::RRxPlWid(width) => write!(f, "R_RX_PL_WID -> {width}"),
+            Decoded::WAckPayload { pipe, payload } => {
+                write!(f, "W_ACK_PAYLOAD {pipe:?} [{} bytes]", payload.len())
+            }
+            Decoded::WTxPayloadNoack(payload) => {
+                write!(f, "W_TX_PAYLOAD_NOACK [{} bytes]", payload.len())
+            }
+            Decoded::Nop => {
+                write!(f, "NOP -> ")?;
+                write_fields(f, &self.status)
+            }
+            Decoded::Unknown { word } => write!(f, "UNKNOWN {word:#04X}"),
+        }
+    }
+}
+
+/// Decodes SPI transactions, following the address width across the trace.
+#[derive(Copy, Clone, Debug)]
+pub struct Disassembler {
+    address_width: AddressWidth,
+}
+
+impl Disassembler {
+    /// Create a disassembler for a trace starting from reset, i.e. with 5 byte addresses.
+    pub const fn new() -> Self {
+        Self::with_setup_aw(registers::SetupAw::new())
+    }
+
+    /// Create a disassembler for a trace starting with the given [`SETUP_AW`](registers::SetupAw).
+    pub const fn with_setup_aw(setup_aw: registers::SetupAw) -> Self {
+        Self {
+            address_width: setup_aw.aw(),
+        }
+    }
+
+    /// Current address width.
+    pub const fn address_width(&self) -> AddressWidth {
+        self.address_width
+    }
+
+    /// Decode one transaction from the bytes exchanged during a single CSN assertion.
+    ///
+    /// Reading or writing [`SETUP_AW`](registers::SetupAw) updates the address width used to
+    /// decode the following transactions.
+    pub fn decode<'a>(&mut self, mosi: &'a [u8], miso: &'a [u8]) -> Result<Transaction<'a>, Error> {
+        if mosi.len() != miso.len() {
+            return Err(Error::LengthMismatch {
+                mosi: mosi.len(),
+                miso: miso.len(),
+            });
+        }
+        let Some(&word) = mosi.first() else {
+            return Err(Error::Empty);
+        };
+        let status = registers::Status::from_bits(miso[0]);
+        let truncated = |expected| Error::Truncated {
+            word,
+            len: mosi.len(),
+            expected,
+        };
+
+        let command = match word {
+            w if w & 0b1100_0000 == 0 => {
+                let read = w & 0b1110_0000 == commands::RRegister::<registers::Config>::WORD;
+                let data = if read { &miso[1..] } else { &mosi[1..] };
+                match self.register(w & 0x1F, data) {
+                    Ok(Some(register)) if read => Decoded::RRegister(register),
+                    Ok(Some(register)) => Decoded::WRegister(register),
+                    Ok(None) => Decoded::UnknownRegister { word },
+                    Err(expected) => {
+                        return Err(expected.map_or(Error::IllegalAddressWidth, truncated));
+                    }
+                }
+            }
+            commands::RRxPayload::<1>::WORD => Decoded::RRxPayload(&miso[1..]),
+            commands::WTxPayload::<1>::WORD => Decoded::WTxPayload(&mosi[1..]),
+            commands::FlushTx::WORD => Decoded::FlushTx,
+            commands::FlushRx::WORD => Decoded::FlushRx,
+            commands::ReuseTxPl::WORD => Decoded::ReuseTxPl,
+            commands::RRxPlWid::WORD => match miso.get(1) {
+                Some(&width) => Decoded::RRxPlWid(width),
+                None => return Err(truncated(2)),
+            },
+            w if w & 0b1111_1000 == commands::WAckPayload::<1>::WORD => {
+                match Pipe::try_from_bits(w & 0b111) {
+                    Ok(pipe) => Decoded::WAckPayload {
+                        pipe,
+                        payload: &mosi[1..],
+                    },
+                    Err(_) => Decoded::Unknown { word },
+                }
+            }
+            commands::WTxPayloadNoack::<1>::WORD => Decoded::WTxPayloadNoack(&mosi[1..]),
+            commands::Nop::WORD => Decoded::Nop,
+            _ => Decoded::Unknown { word },
+        };
+        Ok(Transaction { status, command })
+    }
+
+    /// Decode a register from its data bytes and follow `SETUP_AW`.
+    ///
+    /// Errors with the expected transaction length, or `None` if the address width is illegal.
+    fn register(
+        &mut self,
+        address: u8,
+        data: &[u8],
+    ) -> Result<Option<RegisterValue>, Option<usize>> {
+        let address_register = match address {
+            registers::RxAddrP0::<5>::ADDRESS => Some(RegisterValue::RxAddrP0 as fn(_) -> _),
+            registers::RxAddrP1::<5>::ADDRESS => Some(RegisterValue::RxAddrP1 as fn(_) -> _),
+            registers::TxAddr::<5>::ADDRESS => Some(RegisterValue::TxAddr as fn(_) -> _),
+            _ => None,
+        };
+        if let Some(register) = address_register {
+            let width = match self.address_width {
+                AddressWidth::Illegal => return Err(None),
+                AddressWidth::ThreeByte => 3,
+                AddressWidth::FourByte => 4,
+                AddressWidth::FiveByte => 5,
+            };
+            return match data.get(..width) {
+                Some(bytes) => Ok(Some(register(Address::from_bytes(bytes)))),
+                None => Err(Some(width + 1)),
+            };
+        }
+
+        let Some(&bits) = data.first() else {
+            return Err(Some(2));
+        };
+        let register = RegisterValue::from_byte(address, bits);
+        if let Some(RegisterValue::SetupAw(setup_aw)) = register {
+            self.address_width = setup_aw.aw();
+        }
+        Ok(register)
+    }
+}
+
+impl Default for Disassembler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use std::string::ToString;
+
+    fn decode(disassembler: &mut Disassembler, mosi: &[u8], miso: &[u8]) -> std::string::String {
+        disassembler.decode(mosi, miso).unwrap().to_string()
+    }
+
+    #[test]
+    fn test_registers() {
+        let mut disassembler = Disassembler::new();
+        assert_eq!(
+            decode(&mut disassembler, &[0x07, 0], &[0b0010_1110, 0b0010_1110]),
+            "R_REGISTER STATUS -> { rx_dr: false, tx_ds: true, max_rt: false, rx_p_no: RxFifoEmpty, tx_full: false }"
+        );
+        assert_eq!(
+            decode(&mut disassembler, &[0b0010_0101, 76], &[0b0000_1110, 0]),
+            "W_REGISTER RF_CH { rf_ch: 76 }"
+        );
+        assert_eq!(
+            decode(
+                &mut disassembler,
+                &[0b0010_0000 | 0x18, 0],
+                &[0b0000_1110, 0]
+            ),
+            "REGISTER 0x38"
+        );
+
+        // Address registers follow SETUP_AW
+        let tx_addr = [0b0010_0000 | 0x10, 0x0B, 0x32, 0x7C, 0xDE, 0x61];
+        assert_eq!(
+            decode(&mut disassembler, &tx_addr, &[0b0000_1110; 6]),
+            "W_REGISTER TX_ADDR 0x61DE7C320B"
+        );
+        assert_eq!(
+            decode(&mut disassembler, &[0b0010_0011, 0b01], &[0b0000_1110, 0]),
+            "W_REGISTER SETUP_AW { aw: ThreeByte }"
+        );
+        assert_eq!(disassembler.address_width(), AddressWidth::ThreeByte);
+        assert_eq!(
+            decode(
+                &mut disassembler,
+                &[0x0A, 0, 0, 0],
+                &[0b0000_1110, 0x58, 0x6C, 0x3A]
+            ),
+            "R_REGISTER RX_ADDR_P0 -> 0x3A6C58"
+        );
+        assert_eq!(
+            disassembler
+                .decode(&[0x0B, 0, 0], &[0b0000_1110, 0x58, 0x6C])
+                .unwrap_err(),
+            Error::Truncated {
+                word: 0x0B,
+                len: 3,
+                expected: 4
+            }
+        );
+
+        // Reading SETUP_AW also updates the address width
+        let mut disassembler = Disassembler::new();
+        disassembler.decode(&[0x03, 0], &[0b0000_1110, 0]).unwrap();
+        assert_eq!(
+            disassembler.decode(&[0x10, 0, 0, 0], &[0; 4]).unwrap_err(),
+            Error::IllegalAddressWidth
+        );
+    }
+
+    #[test]
+    fn test_payloads() {
+        let mut disassembler = Disassembler::new();
+        let transaction = disassembler
+            .decode(&[0b0110_0001, 0, 0], &[0b0100_0000, 7, 8])
+            .unwrap();
+        assert!(transaction.status.rx_dr());
+        assert!(matches!(transaction.command, Decoded::RRxPayload(&[7, 8])));
+        assert_eq!(transaction.to_string(), "R_RX_PAYLOAD -> [2 bytes]");
+
+        assert_eq!(
+            decode(&mut disassembler, &[0b0110_0000, 0], &[0b0100_0000, 2]),
+            "R_RX_PL_WID -> 2"
+        );
+        assert_eq!(
+            decode(&mut disassembler, &[0b1010_1011, 1, 2, 3], &[0; 4]),
+            "W_ACK_PAYLOAD P3 [3 bytes]"
+        );
+        assert_eq!(
+            decode(&mut disassembler, &[0b1011_0000, 1], &[0; 2]),
+            "W_TX_PAYLOAD_NOACK [1 bytes]"
+        );
+        assert_eq!(
+            decode(&mut disassembler, &[0b1010_1111, 1], &[0; 2]),
+            "UNKNOWN 0xAF"
+        );
+    }
+
+    #[test]
+    fn test_single_byte_commands() {
+        let mut disassembler = Disassembler::new();
+        assert_eq!(decode(&mut disassembler, &[0b1110_0001], &[0]), "FLUSH_TX");
+        assert_eq!(decode(&mut disassembler, &[0b1110_0010], &[0]), "FLUSH_RX");
+        assert_eq!(
+            decode(&mut disassembler, &[0b1110_0011], &[0]),
+            "REUSE_TX_PL"
+        );
+        assert_eq!(
+            decode(&mut disassembler, &[0xFF], &[0b0001_1110]),
+            "NOP -> { rx_dr: false, tx_ds: false, max_rt: true, rx_p_no: RxFifoEmpty, tx_full: false }"
+        );
+
+        assert_eq!(disassembler.decode(&[], &[]).unwrap_err(), Error::Empty);
+        assert_eq!(
+            disassembler.decode(&[0xFF], &[0, 0]).unwrap_err(),
+            Error::LengthMismatch { mosi: 1, miso: 2 }
+        );
+        assert_eq!(
+            disassembler.decode(&[0b0110_0000], &[0]).unwrap_err(),
+            Error::Truncated {
+                word: 0b0110_0000,
+                len: 1,
+                expected: 2
+            }
+        );
+    }
+}
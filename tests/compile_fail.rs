@@ -0,0 +1,7 @@
+//! Commands with an invalid const parameter fail to compile with the assertion's message.
+
+#[test]
+fn payload_width() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
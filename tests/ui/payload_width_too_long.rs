@@ -0,0 +1,8 @@
+use nrf24l01_commands::commands::{self, SpiBytes};
+
+const VALID: SpiBytes = commands::WTxPayload([0; 32]).spi_bytes();
+const TOO_LONG: SpiBytes = commands::WTxPayload([0; 33]).spi_bytes();
+
+fn main() {
+    let _ = (VALID, TOO_LONG);
+}
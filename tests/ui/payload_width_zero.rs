@@ -0,0 +1,8 @@
+use nrf24l01_commands::commands::{self, SpiBytes};
+
+const VALID: SpiBytes = commands::RRxPayload::<32>::spi_bytes();
+const EMPTY: SpiBytes = commands::RRxPayload::<0>::spi_bytes();
+
+fn main() {
+    let _ = (VALID, EMPTY);
+}